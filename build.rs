@@ -0,0 +1,32 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "ffi")]
+    ffi_harness();
+}
+
+/// Compiles the C test harness for `tests/ffi.rs`, which is not part of the published crate.
+#[cfg(feature = "ffi")]
+fn ffi_harness() {
+    let harness = std::path::Path::new("tests/ffi/harness.c");
+    println!("cargo:rerun-if-changed=include/iso4217.h");
+    println!("cargo:rerun-if-changed={}", harness.display());
+
+    if !harness.exists() {
+        return;
+    }
+
+    cc::Build::new()
+        .file(harness)
+        .include("include")
+        .warnings(true)
+        .extra_warnings(true)
+        .warnings_into_errors(true)
+        .cargo_metadata(false)
+        .compile("iso4217_ffi_harness");
+
+    println!(
+        "cargo:rustc-link-search=native={}",
+        std::env::var("OUT_DIR").unwrap()
+    );
+}
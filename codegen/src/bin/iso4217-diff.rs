@@ -0,0 +1,66 @@
+//! Compares a new version of ISO 4217 list one with the checked-in data.
+//!
+//! ```sh
+//! cargo run -p currency-iso4217-codegen --bin iso4217-diff -- NEW.xml [--old OLD.xml] [--apply]
+//! ```
+//!
+//! Prints a changelog snippet of added, removed, renamed and minor-unit-changed currencies.
+//! With `--apply`, replaces `data/list-one.xml` with the new list and regenerates the sources.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use currency_iso4217_codegen::{
+    diff::Diff, render, render_ts, Dataset, GENERATED, GENERATED_TS, LIST_ONE,
+};
+
+const USAGE: &str = "usage: iso4217-diff NEW.xml [--old OLD.xml] [--apply]";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("codegen crate is nested in the repository");
+
+    let mut new: Option<PathBuf> = None;
+    let mut old = root.join(LIST_ONE);
+    let mut apply = false;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--apply" => apply = true,
+            "--old" => old = args.next().ok_or(USAGE)?.into(),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if new.is_none() && !arg.starts_with('-') => new = Some(arg.into()),
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let new = new.ok_or(USAGE)?;
+
+    let new_xml = fs::read_to_string(&new)?;
+    let diff = Diff::parse(&fs::read_to_string(&old)?, &new_xml)?;
+    print!("{}", diff.changelog());
+
+    if apply {
+        // validate against the other data files before touching any of them
+        let data = Dataset::load_with_list_one(root, &new_xml)?;
+        let (generated, generated_ts) = (render(&data), render_ts(&data));
+
+        fs::write(root.join(LIST_ONE), &new_xml)?;
+        fs::write(root.join(GENERATED), generated)?;
+        fs::write(root.join(GENERATED_TS), generated_ts)?;
+        eprintln!("updated {LIST_ONE}, {GENERATED} and {GENERATED_TS}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+//! Differences between two versions of ISO 4217 list one.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use crate::{aggregate, parse_list_one, CurrencyInfo, Result};
+
+/// A single change between two versions of list one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change {
+    /// A code was added.
+    Added(CurrencyInfo),
+    /// A code was withdrawn.
+    Removed(CurrencyInfo),
+    /// The name of a code changed.
+    Renamed {
+        code: String,
+        from: String,
+        to: String,
+    },
+    /// The numeric code of an alphabetic code changed.
+    Renumbered { code: String, from: u16, to: u16 },
+    /// The minor units of a code changed.
+    MinorUnits {
+        code: String,
+        from: Option<u8>,
+        to: Option<u8>,
+    },
+}
+
+impl Change {
+    /// Gets the alphabetic code the change applies to.
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Added(info) | Self::Removed(info) => &info.code,
+            Self::Renamed { code, .. }
+            | Self::Renumbered { code, .. }
+            | Self::MinorUnits { code, .. } => code,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added(info) => write!(
+                f,
+                "Added `{}` ({:03}, {}, minor units: {})",
+                info.code,
+                info.number,
+                info.name,
+                MinorUnits(info.minor_units)
+            ),
+            Self::Removed(info) => write!(
+                f,
+                "Removed `{}` ({:03}, {})",
+                info.code, info.number, info.name
+            ),
+            Self::Renamed { code, from, to } => {
+                write!(f, "Renamed `{code}`: \"{from}\" -> \"{to}\"")
+            }
+            Self::Renumbered { code, from, to } => {
+                write!(f, "Renumbered `{code}`: {from:03} -> {to:03}")
+            }
+            Self::MinorUnits { code, from, to } => write!(
+                f,
+                "Minor units of `{code}`: {} -> {}",
+                MinorUnits(*from),
+                MinorUnits(*to)
+            ),
+        }
+    }
+}
+
+struct MinorUnits(Option<u8>);
+
+impl fmt::Display for MinorUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(m) => write!(f, "{m}"),
+            None => f.write_str("N.A."),
+        }
+    }
+}
+
+/// The changes between two versions of list one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diff {
+    /// Publication date of the old list.
+    pub old_published: String,
+    /// Publication date of the new list.
+    pub new_published: String,
+    /// Changes, sorted by alphabetic code.
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    /// Compares the contents of two list one XML files.
+    pub fn parse(old: &str, new: &str) -> Result<Self> {
+        let (old_published, old) = parse_list_one(old)?;
+        let (new_published, new) = parse_list_one(new)?;
+
+        Ok(Self {
+            old_published,
+            new_published,
+            changes: diff(&aggregate(&old)?, &aggregate(&new)?),
+        })
+    }
+
+    /// Gets whether the lists are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Renders a Markdown changelog snippet.
+    pub fn changelog(&self) -> String {
+        let mut out = format!(
+            "### ISO 4217 data {} -> {}\n\n",
+            self.old_published, self.new_published
+        );
+        if self.changes.is_empty() {
+            out.push_str("- No changes to list one\n");
+        }
+        for change in self.changes.iter() {
+            // writing to a `String` never fails
+            let _ = writeln!(out, "- {change}");
+        }
+        out
+    }
+}
+
+/// Compares two aggregated currency lists.
+pub fn diff(old: &[CurrencyInfo], new: &[CurrencyInfo]) -> Vec<Change> {
+    let old: BTreeMap<&str, &CurrencyInfo> = old.iter().map(|c| (c.code.as_str(), c)).collect();
+    let new: BTreeMap<&str, &CurrencyInfo> = new.iter().map(|c| (c.code.as_str(), c)).collect();
+    let mut changes = Vec::new();
+
+    for (code, o) in old.iter() {
+        let n = match new.get(code) {
+            Some(n) => n,
+            None => {
+                changes.push(Change::Removed((*o).clone()));
+                continue;
+            }
+        };
+
+        if o.name != n.name {
+            changes.push(Change::Renamed {
+                code: code.to_string(),
+                from: o.name.clone(),
+                to: n.name.clone(),
+            });
+        }
+        if o.number != n.number {
+            changes.push(Change::Renumbered {
+                code: code.to_string(),
+                from: o.number,
+                to: n.number,
+            });
+        }
+        if o.minor_units != n.minor_units {
+            changes.push(Change::MinorUnits {
+                code: code.to_string(),
+                from: o.minor_units,
+                to: n.minor_units,
+            });
+        }
+    }
+
+    changes.extend(
+        new.iter()
+            .filter(|(code, _)| !old.contains_key(*code))
+            .map(|(_, n)| Change::Added((*n).clone())),
+    );
+    changes.sort_by(|a, b| a.code().cmp(b.code()));
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(published: &str, entries: &[(&str, &str, &str, u16, &str)]) -> String {
+        let mut xml = format!("<ISO_4217 Pblshd=\"{published}\"><CcyTbl>");
+        for (country, name, code, number, minor) in entries.iter() {
+            let _ = write!(
+                xml,
+                "<CcyNtry><CtryNm>{country}</CtryNm><CcyNm>{name}</CcyNm><Ccy>{code}</Ccy>\
+                 <CcyNbr>{number}</CcyNbr><CcyMnrUnts>{minor}</CcyMnrUnts></CcyNtry>"
+            );
+        }
+        xml.push_str("</CcyTbl></ISO_4217>");
+        xml
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = list(
+            "2023-01-01",
+            &[
+                ("CROATIA", "Kuna", "HRK", 191, "2"),
+                ("ICELAND", "Iceland Krona", "ISK", 352, "0"),
+                ("VENEZUELA", "Bolívar Soberano", "VES", 928, "2"),
+                ("ZZ08", "No currency", "XXX", 999, "N.A."),
+            ],
+        );
+        let new = list(
+            "2024-06-25",
+            &[
+                ("ICELAND", "Iceland Krona", "ISK", 352, "2"),
+                ("VENEZUELA", "Bolívar Soberano Digital", "VES", 928, "2"),
+                ("ZIMBABWE", "Zimbabwe Gold", "ZWG", 924, "2"),
+                ("ZZ08", "No currency", "XXX", 999, "N.A."),
+            ],
+        );
+
+        let diff = Diff::parse(&old, &new).unwrap();
+        let codes: Vec<&str> = diff.changes.iter().map(Change::code).collect();
+        assert_eq!(codes, ["HRK", "ISK", "VES", "ZWG"]);
+
+        assert_eq!(
+            diff.changelog(),
+            "### ISO 4217 data 2023-01-01 -> 2024-06-25\n\n\
+             - Removed `HRK` (191, Kuna)\n\
+             - Minor units of `ISK`: 0 -> 2\n\
+             - Renamed `VES`: \"Bolívar Soberano\" -> \"Bolívar Soberano Digital\"\n\
+             - Added `ZWG` (924, Zimbabwe Gold, minor units: 2)\n"
+        );
+
+        assert!(Diff::parse(&new, &new).unwrap().is_empty());
+    }
+}
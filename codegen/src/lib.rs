@@ -0,0 +1,853 @@
+//! Code generator for `currency-iso4217`.
+//!
+//! Reads the ISO 4217 lists published by SIX <https://www.six-group.com/en/products-services/financial-information/data-standards.html>:
+//!
+//! - `data/list-one.xml`: current currency and funds code list
+//! - `data/list-three.xml`: historic denominations
+//!
+//! and renders `src/generated.rs`, the single source of the `Currency` enum and its tables, and
+//! `src/generated.d.ts`, the matching TypeScript type for the `wasm` bindings.
+//!
+//! Run `cargo run -p currency-iso4217-codegen` after updating the data files, or compare a newly
+//! published list with `cargo run -p currency-iso4217-codegen --bin iso4217-diff -- NEW.xml`.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::path::Path;
+use std::{error, fs};
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+pub mod diff;
+
+/// Path of the current currency list, relative to the repository root.
+pub const LIST_ONE: &str = "data/list-one.xml";
+/// Path of the historic currency list, relative to the repository root.
+pub const LIST_THREE: &str = "data/list-three.xml";
+/// Path of the amendment list, relative to the repository root.
+pub const AMENDMENTS: &str = "data/amendments.csv";
+/// Path of the generated sources, relative to the repository root.
+pub const GENERATED: &str = "src/generated.rs";
+/// Path of the generated TypeScript definitions, relative to the repository root.
+pub const GENERATED_TS: &str = "src/generated.d.ts";
+
+/// Errors returned while reading the data files.
+#[derive(Debug)]
+pub struct Error(String);
+
+impl Error {
+    fn new<S: Into<String>>(msg: S) -> Self {
+        Self(msg.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<quick_xml::Error> for Error {
+    fn from(err: quick_xml::Error) -> Self {
+        Self(format!("XML error: {err}"))
+    }
+}
+
+impl From<quick_xml::events::attributes::AttrError> for Error {
+    fn from(err: quick_xml::events::attributes::AttrError) -> Self {
+        Self(format!("XML attribute error: {err}"))
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self(format!("I/O error: {err}"))
+    }
+}
+
+/// Result type for the generator.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A country (or other entity) entry in list one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /// Entity name, e.g. `SWITZERLAND`.
+    pub country: String,
+    /// Currency name, e.g. `Swiss Franc`.
+    pub name: String,
+    /// Alphabetic code, `None` for entities without a universal currency.
+    pub code: Option<String>,
+    /// Numeric code.
+    pub number: Option<u16>,
+    /// Minor units, `None` when not applicable (`N.A.`).
+    pub minor_units: Option<u8>,
+    /// Whether the code is a funds code.
+    pub is_fund: bool,
+}
+
+/// A withdrawn currency entry in list three.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoricEntry {
+    /// Entity name, e.g. `CROATIA`.
+    pub country: String,
+    /// Currency name, e.g. `Kuna`.
+    pub name: String,
+    /// Alphabetic code.
+    pub code: String,
+    /// Numeric code, if one was assigned.
+    pub number: Option<u16>,
+    /// Withdrawal date as published, e.g. `2023-01`.
+    pub withdrawn: String,
+}
+
+/// A currency aggregated over all list one entries using its code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CurrencyInfo {
+    /// Alphabetic code.
+    pub code: String,
+    /// Numeric code.
+    pub number: u16,
+    /// Currency name.
+    pub name: String,
+    /// Minor units, `None` when not applicable.
+    pub minor_units: Option<u8>,
+    /// Whether the code is a funds code.
+    pub is_fund: bool,
+    /// Entities using the currency, in list order.
+    pub countries: Vec<String>,
+}
+
+/// An ISO 4217 amendment from the amendment list.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Amendment {
+    /// Amendment number, e.g. `174`.
+    pub number: u16,
+    /// Publication date, e.g. `2023-01-01`.
+    pub published: String,
+    /// Alphabetic codes added by the amendment.
+    pub added: Vec<String>,
+    /// Alphabetic codes withdrawn by the amendment.
+    pub withdrawn: Vec<String>,
+}
+
+/// The parsed contents of both lists and the amendment list.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dataset {
+    /// Publication date of list one, e.g. `2023-01-01`.
+    pub published: String,
+    /// Current currencies, sorted by alphabetic code.
+    pub currencies: Vec<CurrencyInfo>,
+    /// Withdrawn currencies, in list order.
+    pub historic: Vec<HistoricEntry>,
+    /// Tracked amendments, oldest first.
+    pub amendments: Vec<Amendment>,
+}
+
+impl Dataset {
+    /// Loads the dataset from the data files under the repository `root`.
+    pub fn load(root: &Path) -> Result<Self> {
+        let one = fs::read_to_string(root.join(LIST_ONE))?;
+        Self::load_with_list_one(root, &one)
+    }
+
+    /// Loads the dataset under the repository `root`, with the given contents of list one
+    /// instead of the checked-in file, e.g. to validate a new list before replacing it.
+    pub fn load_with_list_one(root: &Path, list_one: &str) -> Result<Self> {
+        let three = fs::read_to_string(root.join(LIST_THREE))?;
+        let amendments = fs::read_to_string(root.join(AMENDMENTS))?;
+        Self::parse(list_one, &three, &amendments)
+    }
+
+    /// Parses the dataset from the contents of list one, list three and the amendment list.
+    pub fn parse(list_one: &str, list_three: &str, amendments: &str) -> Result<Self> {
+        let (published, entries) = parse_list_one(list_one)?;
+        let (_, historic) = parse_list_three(list_three)?;
+        let data = Self {
+            published,
+            currencies: aggregate(&entries)?,
+            historic,
+            amendments: parse_amendments(amendments)?,
+        };
+        data.check_amendments()?;
+        Ok(data)
+    }
+
+    /// Gets the number of the latest tracked amendment.
+    pub fn amendment(&self) -> Option<u16> {
+        self.amendments.last().map(|a| a.number)
+    }
+
+    /// Gets the number of the amendment that added a current code.
+    pub fn added_in(&self, code: &str) -> Option<u16> {
+        self.amendments
+            .iter()
+            .rev()
+            .find(|a| a.added.iter().any(|c| c == code))
+            .map(|a| a.number)
+    }
+
+    fn check_amendments(&self) -> Result<()> {
+        if self.amendments.is_empty() {
+            return Err(Error::new(format!("{AMENDMENTS} lists no amendments")));
+        }
+        for (prev, next) in self.amendments.iter().zip(self.amendments.iter().skip(1)) {
+            if prev.number >= next.number || prev.published > next.published {
+                return Err(Error::new(format!(
+                    "amendment {} is out of order",
+                    next.number
+                )));
+            }
+        }
+        if let Some(last) = self.amendments.last() {
+            if last.published > self.published {
+                return Err(Error::new(format!(
+                    "amendment {} is newer than {LIST_ONE}",
+                    last.number
+                )));
+            }
+        }
+        for amendment in self.amendments.iter() {
+            let n = amendment.number;
+            for code in amendment.added.iter() {
+                let current = self.currency(code).is_some();
+                let historic = self.historic.iter().any(|h| &h.code == code);
+                if !current && !historic {
+                    return Err(Error::new(format!("amendment {n}: unknown code `{code}`")));
+                }
+            }
+            for code in amendment.withdrawn.iter() {
+                if self.currency(code).is_some() {
+                    return Err(Error::new(format!(
+                        "amendment {n}: withdrawn code `{code}` is still current"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets a current currency by alphabetic code.
+    pub fn currency(&self, code: &str) -> Option<&CurrencyInfo> {
+        self.currencies.iter().find(|c| c.code == code)
+    }
+}
+
+/// A flat XML record: child element name to (text, `IsFund` attribute).
+type Record = BTreeMap<String, (String, bool)>;
+
+/// Reads the `Pblshd` root attribute and all `record` elements of an ISO 4217 list.
+fn records(xml: &str, record: &str) -> Result<(String, Vec<Record>)> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut published = String::new();
+    let mut out = Vec::new();
+    let mut current: Option<Record> = None;
+    let mut field: Option<(String, bool)> = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                if name == "ISO_4217" {
+                    for attr in e.attributes() {
+                        let attr = attr?;
+                        if attr.key.as_ref() == b"Pblshd" {
+                            published = attr.unescape_value()?.into_owned();
+                        }
+                    }
+                } else if name == record {
+                    current = Some(Record::new());
+                } else if current.is_some() {
+                    let mut is_fund = false;
+                    for attr in e.attributes() {
+                        let attr = attr?;
+                        if attr.key.as_ref() == b"IsFund" {
+                            is_fund = attr.unescape_value()?.eq_ignore_ascii_case("true");
+                        }
+                    }
+                    field = Some((name, is_fund));
+                }
+            }
+            Event::Text(t) => {
+                if let (Some(rec), Some((name, is_fund))) = (current.as_mut(), field.as_ref()) {
+                    rec.insert(name.clone(), (t.unescape()?.trim().to_owned(), *is_fund));
+                }
+            }
+            Event::End(e) => {
+                if e.name().as_ref() == record.as_bytes() {
+                    out.extend(current.take());
+                }
+                field = None;
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+    }
+
+    if published.is_empty() {
+        return Err(Error::new("missing `Pblshd` publication date"));
+    }
+
+    Ok((published, out))
+}
+
+fn text<'a>(rec: &'a Record, name: &str) -> Option<&'a str> {
+    rec.get(name)
+        .map(|(t, _)| t.as_str())
+        .filter(|t| !t.is_empty())
+}
+
+fn number(rec: &Record, code: &str) -> Result<Option<u16>> {
+    text(rec, "CcyNbr")
+        .map(|n| {
+            n.parse()
+                .map_err(|_| Error::new(format!("{code}: invalid numeric code `{n}`")))
+        })
+        .transpose()
+}
+
+/// Parses list one, returning the publication date and all entries.
+pub fn parse_list_one(xml: &str) -> Result<(String, Vec<Entry>)> {
+    let (published, recs) = records(xml, "CcyNtry")?;
+    let mut entries = Vec::with_capacity(recs.len());
+
+    for rec in recs.iter() {
+        let country = text(rec, "CtryNm").unwrap_or_default().to_owned();
+        let code = text(rec, "Ccy").map(str::to_owned);
+        let label = code.as_deref().unwrap_or(&country);
+        let minor_units = match text(rec, "CcyMnrUnts") {
+            None | Some("N.A.") => None,
+            Some(m) => Some(
+                m.parse()
+                    .map_err(|_| Error::new(format!("{label}: invalid minor units `{m}`")))?,
+            ),
+        };
+
+        entries.push(Entry {
+            name: text(rec, "CcyNm").unwrap_or_default().to_owned(),
+            number: number(rec, label)?,
+            is_fund: rec.get("CcyNm").map(|(_, f)| *f).unwrap_or(false),
+            country,
+            code,
+            minor_units,
+        });
+    }
+
+    Ok((published, entries))
+}
+
+/// Parses list three, returning the publication date and all entries.
+pub fn parse_list_three(xml: &str) -> Result<(String, Vec<HistoricEntry>)> {
+    let (published, recs) = records(xml, "HstrcCcyNtry")?;
+    let mut entries = Vec::with_capacity(recs.len());
+
+    for rec in recs.iter() {
+        let code = text(rec, "Ccy")
+            .ok_or_else(|| Error::new("historic entry without a code"))?
+            .to_owned();
+        entries.push(HistoricEntry {
+            country: text(rec, "CtryNm").unwrap_or_default().to_owned(),
+            name: text(rec, "CcyNm").unwrap_or_default().to_owned(),
+            number: number(rec, &code)?,
+            withdrawn: text(rec, "WthdrwlDt").unwrap_or_default().to_owned(),
+            code,
+        });
+    }
+
+    Ok((published, entries))
+}
+
+/// Parses the amendment list, a CSV file of `amendment,published,added,withdrawn` rows.
+pub fn parse_amendments(csv: &str) -> Result<Vec<Amendment>> {
+    let mut out = Vec::new();
+
+    for (i, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("amendment,") {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let invalid = || Error::new(format!("{AMENDMENTS}:{}: invalid row `{line}`", i + 1));
+        let [number, published, added, withdrawn] = fields[..] else {
+            return Err(invalid());
+        };
+        let codes = |s: &str| s.split_whitespace().map(str::to_owned).collect();
+
+        let date = published.as_bytes();
+        let valid_date = date.len() == 10
+            && date[4] == b'-'
+            && date[7] == b'-'
+            && date
+                .iter()
+                .enumerate()
+                .all(|(i, d)| i == 4 || i == 7 || d.is_ascii_digit());
+        if !valid_date {
+            return Err(invalid());
+        }
+
+        out.push(Amendment {
+            number: number.parse().map_err(|_| invalid())?,
+            published: published.to_owned(),
+            added: codes(added),
+            withdrawn: codes(withdrawn),
+        });
+    }
+
+    Ok(out)
+}
+
+/// Merges list one entries by alphabetic code, checking that they agree with each other.
+pub fn aggregate(entries: &[Entry]) -> Result<Vec<CurrencyInfo>> {
+    let mut map: BTreeMap<&str, CurrencyInfo> = BTreeMap::new();
+
+    for entry in entries.iter() {
+        let code = match entry.code.as_deref() {
+            Some(code) => code,
+            None => continue,
+        };
+        if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(Error::new(format!("invalid alphabetic code `{code}`")));
+        }
+        let number = entry
+            .number
+            .ok_or_else(|| Error::new(format!("{code}: missing numeric code")))?;
+
+        match map.get_mut(code) {
+            Some(info) => {
+                if info.number != number
+                    || info.name != entry.name
+                    || info.minor_units != entry.minor_units
+                    || info.is_fund != entry.is_fund
+                {
+                    return Err(Error::new(format!(
+                        "{code}: entry for `{}` disagrees with earlier entries",
+                        entry.country
+                    )));
+                }
+                info.countries.push(entry.country.clone());
+            }
+            None => {
+                map.insert(
+                    code,
+                    CurrencyInfo {
+                        code: code.to_owned(),
+                        number,
+                        name: entry.name.clone(),
+                        minor_units: entry.minor_units,
+                        is_fund: entry.is_fund,
+                        countries: vec![entry.country.clone()],
+                    },
+                );
+            }
+        }
+    }
+
+    if !map.contains_key("XXX") {
+        return Err(Error::new("XXX is required as the default currency"));
+    }
+
+    Ok(map.into_values().collect())
+}
+
+/// Renders `src/generated.rs` for the dataset.
+pub fn render(data: &Dataset) -> String {
+    let mut out = String::new();
+    // writing to a `String` never fails
+    let _ = render_into(&mut out, data);
+    out
+}
+
+fn render_into(out: &mut String, data: &Dataset) -> fmt::Result {
+    let cur = &data.currencies;
+
+    writeln!(
+        out,
+        "// @generated by `cargo run -p currency-iso4217-codegen` from {LIST_ONE} and {LIST_THREE}."
+    )?;
+    writeln!(
+        out,
+        "// Do not edit by hand, update the data files instead."
+    )?;
+    writeln!(out)?;
+    writeln!(out, "use crate::{{Amendment, Date, HistoricCurrency}};")?;
+    writeln!(out)?;
+
+    let amendment = data.amendment().unwrap_or_default();
+    writeln!(
+        out,
+        "/// Publication date of the ISO 4217 list the tables were generated from."
+    )?;
+    writeln!(
+        out,
+        "pub const PUBLISHED_DATE: Date = {};",
+        date(&data.published)
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "/// Latest ISO 4217 amendment reflected in the tables."
+    )?;
+    writeln!(out, "pub const DATA_AMENDMENT: u16 = {amendment};")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "/// Version of the ISO 4217 data: publication date and amendment number."
+    )?;
+    writeln!(
+        out,
+        "pub const DATA_VERSION: &str = \"{} (amendment {amendment})\";",
+        data.published
+    )?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "/// ISO 4217 currency codes <https://en.wikipedia.org/wiki/ISO_4217>"
+    )?;
+    writeln!(out, "#[repr(u32)]")?;
+    writeln!(out, "#[allow(clippy::zero_prefixed_literal)]")?;
+    writeln!(
+        out,
+        "#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]"
+    )?;
+    writeln!(
+        out,
+        "#[cfg_attr(feature = \"serde\", derive(serde::Deserialize, serde::Serialize))]"
+    )?;
+    writeln!(out, "pub enum Currency {{")?;
+    for c in cur.iter() {
+        writeln!(out, "    /// {}", c.name)?;
+        if c.code == "XXX" {
+            writeln!(out, "    #[default]")?;
+        }
+        writeln!(out, "    {} = {:03},", c.code, c.number)?;
+    }
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    writeln!(out, "impl Currency {{")?;
+    writeln!(
+        out,
+        "    /// All currencies in ISO 4217 list one, sorted by alphabetic code.\n    ///\n    /// Named `LIST` since `ALL` is the Albanian lek."
+    )?;
+    writeln!(out, "    pub const LIST: [Self; {}] = [", cur.len())?;
+    for c in cur.iter() {
+        writeln!(out, "        Self::{},", c.code)?;
+    }
+    writeln!(out, "    ];")?;
+    writeln!(out)?;
+
+    writeln!(out, "    /// Gets the name of the currency (in English).")?;
+    writeln!(out, "    pub const fn name(&self) -> &'static str {{")?;
+    writeln!(out, "        match self {{")?;
+    for c in cur.iter() {
+        writeln!(out, "            Self::{} => {},", c.code, quote(&c.name))?;
+    }
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+
+    writeln!(out, "    /// Gets the alphabetic code of the currency.")?;
+    writeln!(out, "    pub const fn alpha(&self) -> &'static str {{")?;
+    writeln!(out, "        match self {{")?;
+    for c in cur.iter() {
+        writeln!(out, "            Self::{0} => \"{0}\",", c.code)?;
+    }
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "    /// Gets the number of minor units (digits after the decimal separator)."
+    )?;
+    writeln!(out, "    ///")?;
+    writeln!(
+        out,
+        "    /// Returns `None` for codes where minor units are not applicable (precious metals, bond"
+    )?;
+    writeln!(
+        out,
+        "    /// market units, testing and \"no currency\" codes)."
+    )?;
+    writeln!(out, "    pub const fn minor_units(&self) -> Option<u8> {{")?;
+    writeln!(out, "        match self {{")?;
+    for c in cur.iter() {
+        match c.minor_units {
+            Some(m) => writeln!(out, "            Self::{} => Some({m}),", c.code)?,
+            None => writeln!(out, "            Self::{} => None,", c.code)?,
+        }
+    }
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "    /// Gets whether the code is a funds code rather than a currency, e.g. [Currency::USN]."
+    )?;
+    writeln!(out, "    pub const fn is_fund(&self) -> bool {{")?;
+    let funds: Vec<String> = cur
+        .iter()
+        .filter(|c| c.is_fund)
+        .map(|c| format!("Self::{}", c.code))
+        .collect();
+    if funds.is_empty() {
+        writeln!(out, "        false")?;
+    } else {
+        writeln!(out, "        matches!(")?;
+        writeln!(out, "            self,")?;
+        writeln!(out, "            {}", funds.join("\n                | "))?;
+        writeln!(out, "        )")?;
+    }
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "    /// Gets the names of the countries and other entities using the currency, as listed in"
+    )?;
+    writeln!(out, "    /// ISO 4217.")?;
+    writeln!(
+        out,
+        "    pub const fn countries(&self) -> &'static [&'static str] {{"
+    )?;
+    writeln!(out, "        match self {{")?;
+    for c in cur.iter() {
+        let names: Vec<String> = c.countries.iter().map(|n| quote(n)).collect();
+        writeln!(
+            out,
+            "            Self::{} => &[{}],",
+            c.code,
+            names.join(", ")
+        )?;
+    }
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "    /// Gets the currency for an upper-case alphabetic code, e.g. `b\"USD\"`."
+    )?;
+    writeln!(
+        out,
+        "    pub const fn from_alpha(code: &[u8; 3]) -> Option<Self> {{"
+    )?;
+    writeln!(out, "        match code {{")?;
+    for c in cur.iter() {
+        writeln!(out, "            b\"{0}\" => Some(Self::{0}),", c.code)?;
+    }
+    writeln!(out, "            _ => None,")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "    /// Gets the currency for a numeric code, e.g. `840`."
+    )?;
+    writeln!(
+        out,
+        "    pub const fn from_numeric(code: u32) -> Option<Self> {{"
+    )?;
+    writeln!(out, "        match code {{")?;
+    for c in cur.iter() {
+        writeln!(out, "            {} => Some(Self::{}),", c.number, c.code)?;
+    }
+    writeln!(out, "            _ => None,")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "    /// Gets the number of the ISO 4217 amendment that added the currency."
+    )?;
+    writeln!(out, "    ///")?;
+    writeln!(
+        out,
+        "    /// Returns `None` for currencies that predate the tracked [AMENDMENTS]."
+    )?;
+    writeln!(out, "    pub const fn added_in(&self) -> Option<u16> {{")?;
+    writeln!(out, "        match self {{")?;
+    for c in cur.iter() {
+        if let Some(n) = data.added_in(&c.code) {
+            writeln!(out, "            Self::{} => Some({n}),", c.code)?;
+        }
+    }
+    writeln!(out, "            _ => None,")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+
+    writeln!(out, "/// Tracked ISO 4217 amendments, oldest first.")?;
+    writeln!(out, "pub const AMENDMENTS: &[Amendment] = &[")?;
+    for a in data.amendments.iter() {
+        let codes = |codes: &[String]| -> String {
+            let codes: Vec<String> = codes.iter().map(|c| quote(c)).collect();
+            codes.join(", ")
+        };
+        writeln!(out, "    Amendment {{")?;
+        writeln!(out, "        number: {},", a.number)?;
+        writeln!(out, "        published: {},", date(&a.published))?;
+        writeln!(out, "        added: &[{}],", codes(&a.added))?;
+        writeln!(out, "        withdrawn: &[{}],", codes(&a.withdrawn))?;
+        writeln!(out, "    }},")?;
+    }
+    writeln!(out, "];")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "/// Currencies withdrawn from ISO 4217 (list three), in list order."
+    )?;
+    writeln!(out, "pub const HISTORIC: &[HistoricCurrency] = &[")?;
+    for h in data.historic.iter() {
+        writeln!(out, "    HistoricCurrency {{")?;
+        writeln!(out, "        code: \"{}\",", h.code)?;
+        match h.number {
+            Some(n) => writeln!(out, "        number: Some({n}),")?,
+            None => writeln!(out, "        number: None,")?,
+        }
+        writeln!(out, "        name: {},", quote(&h.name))?;
+        writeln!(out, "        country: {},", quote(&h.country))?;
+        writeln!(out, "        withdrawn: {},", quote(&h.withdrawn))?;
+        writeln!(out, "    }},")?;
+    }
+    writeln!(out, "];")
+}
+
+/// Renders `src/generated.d.ts` for the dataset.
+pub fn render_ts(data: &Dataset) -> String {
+    let mut out = String::new();
+    // writing to a `String` never fails
+    let _ = render_ts_into(&mut out, data);
+    out
+}
+
+fn render_ts_into(out: &mut String, data: &Dataset) -> fmt::Result {
+    writeln!(
+        out,
+        "// @generated by `cargo run -p currency-iso4217-codegen` from {LIST_ONE} and {LIST_THREE}."
+    )?;
+    writeln!(
+        out,
+        "// Do not edit by hand, update the data files instead."
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "/** ISO 4217 alphabetic codes, mirroring the Rust `Currency` enum. */"
+    )?;
+    writeln!(out, "export type Currency =")?;
+    let codes: Vec<String> = data
+        .currencies
+        .iter()
+        .map(|c| format!("  | \"{}\"", c.code))
+        .collect();
+    writeln!(out, "{};", codes.join("\n"))
+}
+
+/// Renders a `YYYY-MM-DD` date as a constant `Date` expression.
+fn date(s: &str) -> String {
+    let mut parts = s.splitn(3, '-').map(|p| p.trim_start_matches('0'));
+    let (y, m, d) = (
+        parts.next().unwrap_or_default(),
+        parts.next().unwrap_or_default(),
+        parts.next().unwrap_or_default(),
+    );
+    format!(
+        "match Date::new({y}, {m}, {d}) {{ Some(date) => date, None => panic!(\"invalid date\") }}"
+    )
+}
+
+/// Quotes a string as a Rust literal.
+fn quote(s: &str) -> String {
+    format!("{s:?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root() -> &'static Path {
+        Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+    }
+
+    #[test]
+    fn test_generated_up_to_date() {
+        let data = Dataset::load(root()).unwrap();
+        let generated = fs::read_to_string(root().join(GENERATED)).unwrap();
+
+        assert!(
+            render(&data) == generated,
+            "{GENERATED} is out of date with the data files, run `cargo run -p currency-iso4217-codegen`"
+        );
+
+        let generated = fs::read_to_string(root().join(GENERATED_TS)).unwrap();
+        assert!(
+            render_ts(&data) == generated,
+            "{GENERATED_TS} is out of date with the data files, run `cargo run -p currency-iso4217-codegen`"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let data = Dataset::load(root()).unwrap();
+        let chf = data.currency("CHF").unwrap();
+
+        assert_eq!(chf.number, 756);
+        assert_eq!(chf.minor_units, Some(2));
+        assert_eq!(chf.countries, ["LIECHTENSTEIN", "SWITZERLAND"]);
+        assert!(data.currency("CHW").unwrap().is_fund);
+        assert_eq!(data.currency("XAU").unwrap().minor_units, None);
+        assert!(data.historic.iter().any(|h| h.code == "HRK"));
+    }
+
+    #[test]
+    fn test_amendments() {
+        let data = Dataset::load(root()).unwrap();
+
+        assert_eq!(data.amendment(), Some(174));
+        assert_eq!(data.added_in("VES"), Some(168));
+        assert_eq!(data.added_in("USD"), None);
+
+        // a new list one must still contain the codes added by tracked amendments
+        let one = fs::read_to_string(root().join(LIST_ONE)).unwrap();
+        assert_eq!(Dataset::load_with_list_one(root(), &one).unwrap(), data);
+        let start = one.find("<Ccy>VED</Ccy>").unwrap();
+        let (start, end) = (
+            one[..start].rfind("<CcyNtry>").unwrap(),
+            start + one[start..].find("</CcyNtry>").unwrap() + "</CcyNtry>".len(),
+        );
+        let without_ved = format!("{}{}", &one[..start], &one[end..]);
+        assert!(Dataset::load_with_list_one(root(), &without_ved).is_err());
+
+        assert!(parse_amendments("1,2024-01-01,ZWG").is_err());
+        assert!(parse_amendments("x,2024-01-01,ZWG,").is_err());
+        assert!(parse_amendments("177,2024-6-25,ZWG,").is_err());
+        assert_eq!(
+            parse_amendments("177,2024-06-25,ZWG,ZWL").unwrap()[0].withdrawn,
+            ["ZWL"]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_conflict() {
+        let xml = r#"<ISO_4217 Pblshd="2024-01-01"><CcyTbl>
+            <CcyNtry><CtryNm>A</CtryNm><CcyNm>Euro</CcyNm><Ccy>EUR</Ccy><CcyNbr>978</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+            <CcyNtry><CtryNm>B</CtryNm><CcyNm>Euro</CcyNm><Ccy>EUR</Ccy><CcyNbr>978</CcyNbr><CcyMnrUnts>3</CcyMnrUnts></CcyNtry>
+        </CcyTbl></ISO_4217>"#;
+        let (published, entries) = parse_list_one(xml).unwrap();
+
+        assert_eq!(published, "2024-01-01");
+        assert!(aggregate(&entries).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! Regenerates `src/generated.rs` from the ISO 4217 data files.
+
+use std::path::Path;
+use std::{fs, process};
+
+use currency_iso4217_codegen::{render, render_ts, Dataset, GENERATED, GENERATED_TS};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("codegen crate is nested in the repository");
+
+    let data = match Dataset::load(root) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    };
+
+    for (file, contents) in [(GENERATED, render(&data)), (GENERATED_TS, render_ts(&data))] {
+        let path = root.join(file);
+        if let Err(err) = fs::write(&path, contents) {
+            eprintln!("error: writing {}: {err}", path.display());
+            process::exit(1);
+        }
+    }
+
+    println!(
+        "wrote {GENERATED} and {GENERATED_TS} ({} currencies, {} historic, published {})",
+        data.currencies.len(),
+        data.historic.len(),
+        data.published
+    );
+}
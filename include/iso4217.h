@@ -0,0 +1,50 @@
+/*
+ * ISO 4217 currency codes, C API of the `currency-iso4217` crate (`ffi` feature).
+ *
+ * Currencies are passed as their numeric code, with 0 for unknown currencies.
+ * Returned strings are static and NUL-terminated, and must not be freed.
+ */
+#ifndef ISO4217_H
+#define ISO4217_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Returned by iso4217_minor_units() where minor units are not applicable, e.g. XAU. */
+#define ISO4217_MINOR_UNITS_NA (-1)
+
+/* Returned by iso4217_minor_units() for unknown currencies. */
+#define ISO4217_UNKNOWN (-2)
+
+/*
+ * Looks up a currency by its three-letter alphabetic code, ignoring ASCII case.
+ *
+ * Returns the numeric code, or 0 if `alpha` is NULL or not a known code.
+ */
+uint16_t iso4217_from_alpha(const char *alpha);
+
+/* Returns `numeric` if it is a known numeric code, otherwise 0. */
+uint16_t iso4217_from_numeric(uint16_t numeric);
+
+/* Gets the alphabetic code of a currency, e.g. "USD", or NULL for unknown currencies. */
+const char *iso4217_alpha(uint16_t currency);
+
+/* Gets the name of a currency, e.g. "US Dollar", or NULL for unknown currencies. */
+const char *iso4217_name(uint16_t currency);
+
+/*
+ * Gets the number of minor units of a currency.
+ *
+ * Returns ISO4217_MINOR_UNITS_NA where minor units are not applicable, and
+ * ISO4217_UNKNOWN for unknown currencies.
+ */
+int iso4217_minor_units(uint16_t currency);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ISO4217_H */
@@ -0,0 +1,173 @@
+//! Python bindings for `currency-iso4217`, built as the `currency_iso4217` extension module.
+//!
+//! Currencies are `Currency` objects; functions taking a currency also accept its alphabetic
+//! code as a string. Amounts are integers in minor units.
+
+use pyo3::exceptions::{PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+
+use currency_iso4217::{Currency, Decimal, DecimalError, Money, DATA_VERSION};
+
+/// An ISO 4217 currency.
+#[pyclass(
+    name = "Currency",
+    module = "currency_iso4217",
+    frozen,
+    eq,
+    hash,
+    from_py_object
+)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+struct PyCurrency(Currency);
+
+#[pymethods]
+impl PyCurrency {
+    /// Looks up a currency by its alphabetic code, ignoring case.
+    ///
+    /// Raises `ValueError` for unknown codes, with a suggestion where one is close.
+    #[new]
+    fn new(code: &str) -> PyResult<Self> {
+        code.parse()
+            .map(Self)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Looks up a currency by its alphabetic code, ignoring case, or `None`.
+    #[staticmethod]
+    fn from_alpha(code: &str) -> Option<Self> {
+        code.parse().ok().map(Self)
+    }
+
+    /// Looks up a currency by its numeric code, or `None`.
+    #[staticmethod]
+    fn from_numeric(numeric: u32) -> Option<Self> {
+        Currency::from_numeric(numeric).map(Self)
+    }
+
+    /// Gets the currencies with the given English name, e.g. `"Swiss franc"`.
+    #[staticmethod]
+    fn from_name(name: &str) -> Vec<Self> {
+        Currency::from_name(name).into_iter().map(Self).collect()
+    }
+
+    /// Gets all currencies, sorted by alphabetic code.
+    #[staticmethod]
+    fn list() -> Vec<Self> {
+        Currency::LIST.into_iter().map(Self).collect()
+    }
+
+    /// Alphabetic code, e.g. `"USD"`.
+    #[getter]
+    fn alpha(&self) -> &'static str {
+        self.0.alpha()
+    }
+
+    /// Numeric code, e.g. `840`.
+    #[getter]
+    fn numeric(&self) -> u32 {
+        self.0.into()
+    }
+
+    /// Name (in English), e.g. `"US Dollar"`.
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    /// Number of minor units, or `None` where not applicable.
+    #[getter]
+    fn minor_units(&self) -> Option<u8> {
+        self.0.minor_units()
+    }
+
+    /// Decimal exponent used for amounts, zero where minor units are not applicable.
+    #[getter]
+    fn exponent(&self) -> u8 {
+        self.0.exponent()
+    }
+
+    /// Whether the code is a funds code rather than a currency.
+    #[getter]
+    fn is_fund(&self) -> bool {
+        self.0.is_fund()
+    }
+
+    /// Names of the countries and other entities using the currency.
+    #[getter]
+    fn countries(&self) -> Vec<&'static str> {
+        self.0.countries().to_vec()
+    }
+
+    /// Formats an amount in minor units, e.g. `12345` as `"123.45 USD"`.
+    fn format(&self, minor: i128) -> String {
+        Money::from_minor(self.0, minor).to_string()
+    }
+
+    fn __str__(&self) -> &'static str {
+        self.0.alpha()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Currency('{}')", self.0.alpha())
+    }
+}
+
+/// A currency argument: a [PyCurrency] or an alphabetic code.
+#[derive(FromPyObject)]
+enum CurrencyArg {
+    Currency(PyCurrency),
+    Code(String),
+}
+
+impl CurrencyArg {
+    fn currency(&self) -> PyResult<Currency> {
+        match self {
+            Self::Currency(cur) => Ok(cur.0),
+            Self::Code(code) => PyCurrency::new(code).map(|cur| cur.0),
+        }
+    }
+}
+
+/// Formats an amount in minor units, e.g. `format_amount("USD", 12345) == "123.45 USD"`.
+#[pyfunction]
+fn format_amount(currency: CurrencyArg, minor: i128) -> PyResult<String> {
+    Ok(Money::from_minor(currency.currency()?, minor).to_string())
+}
+
+/// Formats an amount in minor units as a plain decimal number, e.g. `"123.45"`.
+#[pyfunction]
+fn format_decimal(currency: CurrencyArg, minor: i128) -> PyResult<String> {
+    Ok(Money::from_minor(currency.currency()?, minor)
+        .to_decimal()
+        .to_string())
+}
+
+/// Parses a plain decimal amount, e.g. `"123.45"`, into minor units of a currency.
+///
+/// Raises `ValueError` if the amount is not a decimal number or has more decimal places than
+/// the currency allows.
+#[pyfunction]
+fn to_minor_units(currency: CurrencyArg, amount: &str) -> PyResult<i128> {
+    let currency = currency.currency()?;
+    let value: Decimal = amount
+        .parse()
+        .map_err(|err| PyValueError::new_err(format!("{err}: {amount:?}")))?;
+
+    Money::try_from_decimal(currency, value)
+        .map(|money| money.amount())
+        .map_err(|err| match err {
+            DecimalError::Overflow => PyOverflowError::new_err(err.to_string()),
+            _ => PyValueError::new_err(format!("{err} for {}: {amount:?}", currency.alpha())),
+        })
+}
+
+#[pymodule]
+#[pyo3(name = "currency_iso4217")]
+fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyCurrency>()?;
+    m.add_function(wrap_pyfunction!(format_amount, m)?)?;
+    m.add_function(wrap_pyfunction!(format_decimal, m)?)?;
+    m.add_function(wrap_pyfunction!(to_minor_units, m)?)?;
+    m.add("DATA_VERSION", DATA_VERSION)?;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+import unittest
+
+from currency_iso4217 import (
+    DATA_VERSION,
+    Currency,
+    format_amount,
+    format_decimal,
+    to_minor_units,
+)
+
+
+class CurrencyTest(unittest.TestCase):
+    def test_lookup(self):
+        usd = Currency("USD")
+        self.assertEqual(usd, Currency("usd"))
+        self.assertEqual(usd, Currency.from_alpha("Usd"))
+        self.assertEqual(usd, Currency.from_numeric(840))
+        self.assertIsNone(Currency.from_alpha("QQQ"))
+        self.assertIsNone(Currency.from_numeric(1))
+
+        self.assertEqual(Currency.from_name("Swiss franc"), [Currency("CHF")])
+        self.assertIn(Currency("MXN"), Currency.from_name("peso"))
+        self.assertEqual(Currency.from_name("not a currency"), [])
+
+        with self.assertRaisesRegex(ValueError, "did you mean USD"):
+            Currency("UDS")
+
+    def test_metadata(self):
+        usd = Currency("USD")
+        self.assertEqual(usd.alpha, "USD")
+        self.assertEqual(usd.numeric, 840)
+        self.assertEqual(usd.name, "US Dollar")
+        self.assertEqual(usd.minor_units, 2)
+        self.assertEqual(usd.exponent, 2)
+        self.assertFalse(usd.is_fund)
+        self.assertIn("UNITED STATES OF AMERICA (THE)", usd.countries)
+
+        xau = Currency("XAU")
+        self.assertIsNone(xau.minor_units)
+        self.assertEqual(xau.exponent, 0)
+        self.assertTrue(Currency("USN").is_fund)
+
+        self.assertEqual(str(usd), "USD")
+        self.assertEqual(repr(usd), "Currency('USD')")
+        self.assertEqual(len({usd, Currency("usd"), Currency("EUR")}), 2)
+
+        codes = [c.alpha for c in Currency.list()]
+        self.assertEqual(codes, sorted(codes))
+        self.assertIn("EUR", codes)
+        self.assertTrue(DATA_VERSION)
+
+    def test_formatting(self):
+        self.assertEqual(format_amount("USD", 12345), "123.45 USD")
+        self.assertEqual(format_amount(Currency("JPY"), -500), "-500 JPY")
+        self.assertEqual(format_decimal("KWD", 1005), "1.005")
+        self.assertEqual(Currency("EUR").format(5), "0.05 EUR")
+        self.assertEqual(format_amount("USD", 10**30), "10000000000000000000000000000.00 USD")
+
+        with self.assertRaises(ValueError):
+            format_amount("QQQ", 1)
+        with self.assertRaises(TypeError):
+            format_amount(840, 1)
+
+    def test_to_minor_units(self):
+        self.assertEqual(to_minor_units("USD", "123.45"), 12345)
+        self.assertEqual(to_minor_units(Currency("JPY"), "500"), 500)
+        self.assertEqual(to_minor_units("BHD", "-1.5"), -1500)
+
+        with self.assertRaisesRegex(ValueError, "JPY"):
+            to_minor_units("JPY", "1.5")
+        with self.assertRaises(ValueError):
+            to_minor_units("USD", "12,34")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,409 @@
+//! `iso4217`: look up and list ISO 4217 currency codes.
+//!
+//! ```sh
+//! iso4217 lookup 784 usd "swiss"
+//! iso4217 list --kind fund --format csv
+//! iso4217 list --country switzerland --minor-units 2 --format json
+//! ```
+
+use std::fmt::Write;
+use std::{env, process};
+
+use currency_iso4217::{Currency, DATA_VERSION};
+
+const USAGE: &str = "\
+usage: iso4217 lookup QUERY... [--format FORMAT]
+       iso4217 list [--kind KIND] [--country NAME] [--minor-units N|none] [--format FORMAT]
+       iso4217 version
+
+QUERY is an alphabetic code (USD), a numeric code (840) or part of a currency name (dollar).
+KIND is one of: currency, fund, metal, special.
+FORMAT is one of: table (default), json, csv.";
+
+/// The kind of an ISO 4217 code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Currency,
+    Fund,
+    Metal,
+    Special,
+}
+
+impl Kind {
+    fn of(cur: Currency) -> Self {
+        match cur {
+            _ if cur.is_fund() => Self::Fund,
+            Currency::XAG | Currency::XAU | Currency::XPD | Currency::XPT => Self::Metal,
+            _ if cur.minor_units().is_none() => Self::Special,
+            _ => Self::Currency,
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "currency" => Some(Self::Currency),
+            "fund" => Some(Self::Fund),
+            "metal" => Some(Self::Metal),
+            "special" => Some(Self::Special),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Currency => "currency",
+            Self::Fund => "fund",
+            Self::Metal => "metal",
+            Self::Special => "special",
+        }
+    }
+}
+
+/// Output format.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl Format {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "table" => Some(Self::Table),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Filters for the `list` command.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Filter {
+    kind: Option<Kind>,
+    country: Option<String>,
+    minor_units: Option<Option<u8>>,
+}
+
+impl Filter {
+    fn matches(&self, cur: Currency) -> bool {
+        self.kind.map_or(true, |k| Kind::of(cur) == k)
+            && self.minor_units.map_or(true, |m| cur.minor_units() == m)
+            && self.country.as_deref().map_or(true, |c| {
+                cur.countries()
+                    .iter()
+                    .any(|name| contains_ignore_case(name, c))
+            })
+    }
+}
+
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+/// Looks up currencies by alphabetic code, numeric code or name substring.
+fn lookup(query: &str) -> Vec<Currency> {
+    let query = query.trim();
+
+    if query.len() == 3 && query.bytes().all(|b| b.is_ascii_alphabetic()) {
+        let code = query.to_ascii_uppercase();
+        if let Ok(code) = <[u8; 3]>::try_from(code.as_bytes()) {
+            if let Some(cur) = Currency::from_alpha(&code) {
+                return vec![cur];
+            }
+        }
+    }
+
+    if !query.is_empty() && query.bytes().all(|b| b.is_ascii_digit()) {
+        return query
+            .parse()
+            .ok()
+            .and_then(Currency::from_numeric)
+            .into_iter()
+            .collect();
+    }
+
+    Currency::LIST
+        .into_iter()
+        .filter(|cur| contains_ignore_case(cur.name(), query))
+        .collect()
+}
+
+fn render(currencies: &[Currency], format: Format) -> String {
+    let mut out = String::new();
+    // writing to a `String` never fails
+    let _ = match format {
+        Format::Table => render_table(&mut out, currencies),
+        Format::Json => render_json(&mut out, currencies),
+        Format::Csv => render_csv(&mut out, currencies),
+    };
+    out
+}
+
+fn minor_units(cur: Currency) -> String {
+    cur.minor_units()
+        .map(|m| m.to_string())
+        .unwrap_or_else(|| "N.A.".into())
+}
+
+fn render_table(out: &mut String, currencies: &[Currency]) -> std::fmt::Result {
+    let width = currencies
+        .iter()
+        .map(|c| c.name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    writeln!(
+        out,
+        "CODE  NUM  MINOR  KIND      {:width$}  COUNTRIES",
+        "NAME"
+    )?;
+    for &cur in currencies.iter() {
+        writeln!(
+            out,
+            "{}   {:03}  {:<5}  {:<8}  {:width$}  {}",
+            cur.alpha(),
+            u32::from(cur),
+            minor_units(cur),
+            Kind::of(cur).as_str(),
+            cur.name(),
+            cur.countries().join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+fn json_str(out: &mut String, s: &str) -> std::fmt::Result {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    Ok(())
+}
+
+fn render_json(out: &mut String, currencies: &[Currency]) -> std::fmt::Result {
+    out.push('[');
+    for (i, &cur) in currencies.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write!(
+            out,
+            "{{\"code\":\"{}\",\"number\":{},",
+            cur.alpha(),
+            u32::from(cur)
+        )?;
+        match cur.minor_units() {
+            Some(m) => write!(out, "\"minor_units\":{m},")?,
+            None => out.push_str("\"minor_units\":null,"),
+        }
+        out.push_str("\"name\":");
+        json_str(out, cur.name())?;
+        write!(
+            out,
+            ",\"kind\":\"{}\",\"countries\":[",
+            Kind::of(cur).as_str()
+        )?;
+        for (j, country) in cur.countries().iter().enumerate() {
+            if j > 0 {
+                out.push(',');
+            }
+            json_str(out, country)?;
+        }
+        out.push_str("]}");
+    }
+    out.push_str("]\n");
+    Ok(())
+}
+
+fn csv_field(out: &mut String, s: &str) {
+    if s.contains([',', '"', '\n']) {
+        out.push('"');
+        out.push_str(&s.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(s);
+    }
+}
+
+fn render_csv(out: &mut String, currencies: &[Currency]) -> std::fmt::Result {
+    writeln!(out, "code,number,minor_units,name,kind,countries")?;
+    for &cur in currencies.iter() {
+        write!(out, "{},{:03},", cur.alpha(), u32::from(cur))?;
+        if let Some(m) = cur.minor_units() {
+            write!(out, "{m}")?;
+        }
+        out.push(',');
+        csv_field(out, cur.name());
+        write!(out, ",{},", Kind::of(cur).as_str())?;
+        csv_field(out, &cur.countries().join(";"));
+        out.push('\n');
+    }
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<String, String> {
+    let mut format = Format::default();
+    let mut filter = Filter::default();
+    let mut positional = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |name: &str| {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| format!("missing value for {name}"))
+        };
+        match arg.as_str() {
+            "-f" | "--format" => {
+                let v = value(arg)?;
+                format = Format::parse(&v).ok_or_else(|| format!("unknown format `{v}`"))?;
+            }
+            "--kind" => {
+                let v = value(arg)?;
+                filter.kind = Some(Kind::parse(&v).ok_or_else(|| format!("unknown kind `{v}`"))?);
+            }
+            "--country" => filter.country = Some(value(arg)?),
+            "--minor-units" => {
+                let v = value(arg)?;
+                filter.minor_units = Some(match v.to_ascii_lowercase().as_str() {
+                    "none" | "n.a." => None,
+                    n => Some(
+                        n.parse()
+                            .map_err(|_| format!("invalid minor units `{v}`"))?,
+                    ),
+                });
+            }
+            "-h" | "--help" => return Ok(format!("{USAGE}\n")),
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                return Err(format!("unknown option `{arg}`"))
+            }
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    match positional.split_first() {
+        Some((&"lookup", queries)) if !queries.is_empty() => {
+            let mut found: Vec<Currency> = Vec::new();
+            for query in queries.iter() {
+                let matches = lookup(query);
+                if matches.is_empty() {
+                    let suggestions: Vec<&str> =
+                        Currency::suggest(query).iter().map(|c| c.alpha()).collect();
+                    return Err(match suggestions.is_empty() {
+                        true => format!("no currency matches `{query}`"),
+                        false => format!(
+                            "no currency matches `{query}`, did you mean {}?",
+                            suggestions.join(", ")
+                        ),
+                    });
+                }
+                for cur in matches {
+                    if !found.contains(&cur) {
+                        found.push(cur);
+                    }
+                }
+            }
+            Ok(render(&found, format))
+        }
+        Some((&"list", [])) => {
+            let found: Vec<Currency> = Currency::LIST
+                .into_iter()
+                .filter(|&c| filter.matches(c))
+                .collect();
+            Ok(render(&found, format))
+        }
+        Some((&"version", [])) => Ok(format!("{DATA_VERSION}\n")),
+        _ => Err(USAGE.into()),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(out) => print!("{out}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> Result<String, String> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        run(&args)
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup("784"), [Currency::AED]);
+        assert_eq!(lookup("usd"), [Currency::USD]);
+        assert_eq!(lookup("944"), [Currency::AZN]);
+        assert!(lookup("dollar").contains(&Currency::AUD));
+        assert!(lookup("123").is_empty());
+    }
+
+    #[test]
+    fn test_list_filter() {
+        let funds = Filter {
+            kind: Some(Kind::Fund),
+            ..Default::default()
+        };
+        assert!(funds.matches(Currency::USN));
+        assert!(!funds.matches(Currency::USD));
+
+        let swiss = Filter {
+            country: Some("switzerland".into()),
+            minor_units: Some(Some(2)),
+            ..Default::default()
+        };
+        let found: Vec<Currency> = Currency::LIST
+            .into_iter()
+            .filter(|&c| swiss.matches(c))
+            .collect();
+        assert_eq!(found, [Currency::CHE, Currency::CHF, Currency::CHW]);
+
+        assert_eq!(Kind::of(Currency::XAU), Kind::Metal);
+        assert_eq!(Kind::of(Currency::XDR), Kind::Special);
+        assert_eq!(Kind::of(Currency::XOF), Kind::Currency);
+    }
+
+    #[test]
+    fn test_output() {
+        assert_eq!(
+            run_args(&["lookup", "CHF", "--format", "csv"]).unwrap(),
+            "code,number,minor_units,name,kind,countries\n\
+             CHF,756,2,Swiss Franc,currency,LIECHTENSTEIN;SWITZERLAND\n"
+        );
+        assert_eq!(
+            run_args(&["lookup", "959", "-f", "json"]).unwrap(),
+            "[{\"code\":\"XAU\",\"number\":959,\"minor_units\":null,\"name\":\"Gold\",\
+             \"kind\":\"metal\",\"countries\":[\"ZZ08_Gold\"]}]\n"
+        );
+
+        let table = run_args(&["lookup", "JPY"]).unwrap();
+        assert!(table.starts_with("CODE  NUM  MINOR"));
+        assert!(table.contains("JPY   392  0      currency  Yen"));
+
+        assert_eq!(
+            run_args(&["lookup", "UDS"]),
+            Err("no currency matches `UDS`, did you mean USD, UZS, AUD, BSD, RSD?".into())
+        );
+        assert!(run_args(&["list", "--kind", "coin"]).is_err());
+        assert!(run_args(&["frobnicate"]).is_err());
+    }
+}
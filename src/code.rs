@@ -0,0 +1,228 @@
+//! Open alphabetic currency codes, including codes not (yet) known to this crate.
+
+use crate::std::{fmt, str::FromStr};
+use crate::Currency;
+
+/// A three-letter alphabetic currency code, known to ISO 4217 or not.
+///
+/// Unlike [Currency], which maps unknown codes to [Currency::XXX], a [CurrencyCode] keeps the
+/// original code, e.g. X-prefixed private-use codes or codes added by a newer ISO 4217 amendment,
+/// so they can be forwarded unchanged.
+///
+/// With the `serde` feature, a [CurrencyCode] serializes as its code string, the same as
+/// [Currency] in human-readable formats like JSON.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CurrencyCode([u8; 3]);
+
+impl CurrencyCode {
+    /// Creates a new [CurrencyCode], returning `None` unless the code is three ASCII upper-case
+    /// letters.
+    pub const fn new(code: [u8; 3]) -> Option<Self> {
+        if code[0].is_ascii_uppercase()
+            && code[1].is_ascii_uppercase()
+            && code[2].is_ascii_uppercase()
+        {
+            Some(Self(code))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new [CurrencyCode] for a known [Currency].
+    pub const fn from_currency(currency: Currency) -> Self {
+        let code = currency.alpha().as_bytes();
+        Self([code[0], code[1], code[2]])
+    }
+
+    /// Gets the code as bytes.
+    pub const fn as_bytes(&self) -> &[u8; 3] {
+        &self.0
+    }
+
+    /// Gets the code as a string slice.
+    pub fn as_str(&self) -> &str {
+        // only ASCII upper-case letters are accepted on construction
+        crate::std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Gets the [Currency] for the code, `None` if the code is unknown.
+    pub const fn currency(&self) -> Option<Currency> {
+        Currency::from_alpha(&self.0)
+    }
+
+    /// Gets whether the code is a known [Currency].
+    pub const fn is_known(&self) -> bool {
+        self.currency().is_some()
+    }
+}
+
+impl From<Currency> for CurrencyCode {
+    fn from(val: Currency) -> Self {
+        Self::from_currency(val)
+    }
+}
+
+impl From<&Currency> for CurrencyCode {
+    fn from(val: &Currency) -> Self {
+        (*val).into()
+    }
+}
+
+impl From<CurrencyCode> for Option<Currency> {
+    fn from(val: CurrencyCode) -> Self {
+        val.currency()
+    }
+}
+
+impl From<CurrencyCode> for [u8; 3] {
+    fn from(val: CurrencyCode) -> Self {
+        val.0
+    }
+}
+
+impl TryFrom<[u8; 3]> for CurrencyCode {
+    type Error = InvalidCurrencyCode;
+
+    fn try_from(val: [u8; 3]) -> Result<Self, Self::Error> {
+        Self::new(val).ok_or(InvalidCurrencyCode)
+    }
+}
+
+impl TryFrom<&[u8]> for CurrencyCode {
+    type Error = InvalidCurrencyCode;
+
+    fn try_from(val: &[u8]) -> Result<Self, Self::Error> {
+        <[u8; 3]>::try_from(val)
+            .map_err(|_| InvalidCurrencyCode)?
+            .try_into()
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = InvalidCurrencyCode;
+
+    /// Parses a code of exactly three ASCII upper-case letters.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.as_bytes().try_into()
+    }
+}
+
+impl PartialEq<Currency> for CurrencyCode {
+    fn eq(&self, other: &Currency) -> bool {
+        self.0 == *other.alpha().as_bytes()
+    }
+}
+
+impl PartialEq<CurrencyCode> for Currency {
+    fn eq(&self, other: &CurrencyCode) -> bool {
+        other == self
+    }
+}
+
+impl fmt::Debug for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CurrencyCode").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when a [CurrencyCode] is not three ASCII upper-case letters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidCurrencyCode;
+
+impl fmt::Display for InvalidCurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "currency code must be three upper-case letters")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidCurrencyCode {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CurrencyCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CurrencyCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = CurrencyCode;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a three-letter upper-case currency code")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse()
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_currency_code() {
+        let usd = CurrencyCode::from(Currency::USD);
+        assert_eq!(usd.as_str(), "USD");
+        assert_eq!(usd.currency(), Some(Currency::USD));
+        assert_eq!(usd, Currency::USD);
+        assert_eq!(Option::<Currency>::from(usd), Some(Currency::USD));
+
+        let private: CurrencyCode = "XBT".parse().unwrap();
+        assert!(!private.is_known());
+        assert_eq!(private.currency(), None);
+        assert_eq!(private.as_bytes(), b"XBT");
+        assert_eq!(private.to_string(), "XBT");
+        assert_eq!(Currency::from(private.as_bytes()), Currency::XXX);
+
+        assert_eq!(CurrencyCode::new(*b"ZWG"), "ZWG".parse().ok());
+        assert_eq!(CurrencyCode::new(*b"usd"), None);
+        assert_eq!("US".parse::<CurrencyCode>(), Err(InvalidCurrencyCode));
+        assert_eq!("USDX".parse::<CurrencyCode>(), Err(InvalidCurrencyCode));
+        assert_eq!("U$D".parse::<CurrencyCode>(), Err(InvalidCurrencyCode));
+        assert_eq!(
+            CurrencyCode::try_from(&b"EUR"[..]),
+            Ok(CurrencyCode::from(Currency::EUR))
+        );
+
+        for cur in Currency::LIST {
+            assert_eq!(CurrencyCode::from(cur).currency(), Some(cur));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let usd = CurrencyCode::from(Currency::USD);
+        assert_eq!(
+            serde_json::to_string(&usd).unwrap(),
+            serde_json::to_string(&Currency::USD).unwrap()
+        );
+
+        let private: CurrencyCode = serde_json::from_str("\"XBT\"").unwrap();
+        assert_eq!(private.as_str(), "XBT");
+        assert_eq!(
+            serde_json::from_str::<CurrencyCode>("\"USD\"").unwrap(),
+            Currency::USD
+        );
+        assert!(serde_json::from_str::<CurrencyCode>("\"usd\"").is_err());
+    }
+}
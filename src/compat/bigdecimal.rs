@@ -0,0 +1,182 @@
+//! Conversions with [`bigdecimal::BigDecimal`], enabled by the `bigdecimal` feature.
+
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::{BigDecimal, RoundingMode, Zero};
+
+use crate::{Currency, Decimal, DecimalError, Money, Rounding};
+
+impl From<Rounding> for RoundingMode {
+    fn from(val: Rounding) -> Self {
+        match val {
+            Rounding::Down => Self::Down,
+            Rounding::Up => Self::Up,
+            Rounding::Floor => Self::Floor,
+            Rounding::Ceiling => Self::Ceiling,
+            Rounding::HalfUp => Self::HalfUp,
+            Rounding::HalfDown => Self::HalfDown,
+            Rounding::HalfEven => Self::HalfEven,
+        }
+    }
+}
+
+impl From<Decimal> for BigDecimal {
+    fn from(val: Decimal) -> Self {
+        Self::new(BigInt::from(val.mantissa()), val.scale() as i64)
+    }
+}
+
+/// Largest number of integer digits of an `i128`.
+const MAX_DIGITS: i64 = 39;
+
+/// Fails with [DecimalError::Overflow] if the integer part of `val` has more digits than any
+/// `i128`, before rescaling it allocates and computes a power of ten as large as its exponent.
+fn check_magnitude(val: &BigDecimal) -> Result<(), DecimalError> {
+    let int_digits = (val.digits() as i64).saturating_sub(val.fractional_digit_count());
+    if int_digits > MAX_DIGITS && !val.is_zero() {
+        Err(DecimalError::Overflow)
+    } else {
+        Ok(())
+    }
+}
+
+impl TryFrom<&BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    /// Fails with [DecimalError::Overflow] if the value does not fit in an `i128` mantissa with a
+    /// scale of at most [Decimal::MAX_SCALE].
+    fn try_from(val: &BigDecimal) -> Result<Self, Self::Error> {
+        check_magnitude(val)?;
+        let (_, scale) = val.as_bigint_and_exponent();
+        let val = if scale < 0 {
+            val.with_scale(0)
+        } else if scale > Decimal::MAX_SCALE as i64 {
+            val.normalized()
+        } else {
+            val.clone()
+        };
+
+        let (digits, scale) = val.as_bigint_and_exponent();
+        let mantissa = i128::try_from(&digits).map_err(|_| DecimalError::Overflow)?;
+        let scale = u8::try_from(scale).map_err(|_| DecimalError::Overflow)?;
+
+        Decimal::new(mantissa, scale).ok_or(DecimalError::Overflow)
+    }
+}
+
+impl TryFrom<BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(val: BigDecimal) -> Result<Self, Self::Error> {
+        (&val).try_into()
+    }
+}
+
+impl Money {
+    /// Creates a new [Money] from a [`bigdecimal::BigDecimal`] amount in major units.
+    ///
+    /// Without `rounding`, fails with [DecimalError::Precision] if the value has more decimal
+    /// places than the minor units of the currency, e.g. `1.234` USD.
+    pub fn from_bigdecimal(
+        currency: Currency,
+        value: &BigDecimal,
+        rounding: Option<Rounding>,
+    ) -> Result<Self, DecimalError> {
+        check_magnitude(value)?;
+        let exp = currency.exponent() as i64;
+        let value = match rounding {
+            Some(rounding) => value.with_scale_round(exp, rounding.into()),
+            None => {
+                let normal = value.normalized();
+                if normal.as_bigint_and_exponent().1 > exp {
+                    return Err(DecimalError::Precision);
+                }
+                normal.with_scale(exp)
+            }
+        };
+
+        let (digits, _) = value.as_bigint_and_exponent();
+        i128::try_from(&digits)
+            .map(|amount| Self::from_minor(currency, amount))
+            .map_err(|_| DecimalError::Overflow)
+    }
+
+    /// Gets the amount as a [`bigdecimal::BigDecimal`] in major units.
+    pub fn to_bigdecimal(&self) -> BigDecimal {
+        self.to_decimal().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bd(s: &str) -> BigDecimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_bigdecimal() {
+        assert_eq!(
+            Money::from_bigdecimal(Currency::USD, &bd("1.23"), None),
+            Ok(Money::from_minor(Currency::USD, 123))
+        );
+        assert_eq!(
+            Money::from_bigdecimal(Currency::USD, &bd("1.2300"), None),
+            Ok(Money::from_minor(Currency::USD, 123))
+        );
+        assert_eq!(
+            Money::from_bigdecimal(Currency::USD, &bd("1.234"), None),
+            Err(DecimalError::Precision)
+        );
+        assert_eq!(
+            Money::from_bigdecimal(Currency::USD, &bd("-1.235"), Some(Rounding::HalfUp)),
+            Ok(Money::from_minor(Currency::USD, -124))
+        );
+        assert_eq!(
+            Money::from_bigdecimal(Currency::JPY, &bd("12e3"), None),
+            Ok(Money::from_minor(Currency::JPY, 12_000))
+        );
+        assert_eq!(
+            Money::from_bigdecimal(Currency::USD, &bd("1e40"), None),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            Money::from_bigdecimal(Currency::JPY, &bd("0e20000000"), None),
+            Ok(Money::from_minor(Currency::JPY, 0))
+        );
+    }
+
+    #[test]
+    fn test_huge_exponent() {
+        // fails before computing a 20-million-digit power of ten
+        let huge = bd("1e20000000");
+        assert_eq!(Decimal::try_from(&huge), Err(DecimalError::Overflow));
+        assert_eq!(
+            Money::from_bigdecimal(Currency::USD, &huge, None),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            Money::from_bigdecimal(Currency::USD, &huge, Some(Rounding::HalfEven)),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            Money::from_bigdecimal(Currency::USD, &bd("-1e39"), Some(Rounding::HalfEven)),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            Decimal::try_from(bd("1e38")).map(|d| d.mantissa()),
+            Ok(10i128.pow(38))
+        );
+    }
+
+    #[test]
+    fn test_to_bigdecimal() {
+        assert_eq!(
+            Money::from_minor(Currency::KWD, 1234).to_bigdecimal(),
+            bd("1.234")
+        );
+        assert_eq!(Decimal::try_from(bd("1.50")), Ok("1.50".parse().unwrap()));
+        assert_eq!(Decimal::try_from(bd("1.5e3")), Ok(Decimal::from(1500)));
+        assert_eq!(Decimal::try_from(bd("1e-40")), Err(DecimalError::Overflow));
+    }
+}
@@ -0,0 +1,6 @@
+//! Conversions with third-party decimal types, each behind its own feature.
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
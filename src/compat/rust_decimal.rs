@@ -0,0 +1,104 @@
+//! Conversions with [`rust_decimal::Decimal`], enabled by the `rust_decimal` feature.
+
+use rust_decimal::Decimal as RustDecimal;
+
+use crate::{Currency, Decimal, DecimalError, Money, Rounding};
+
+impl From<RustDecimal> for Decimal {
+    fn from(val: RustDecimal) -> Self {
+        // rust_decimal has a 96-bit mantissa and a scale of at most 28, which always fit
+        Self::from_parts(val.mantissa(), val.scale() as u8)
+    }
+}
+
+impl TryFrom<Decimal> for RustDecimal {
+    type Error = DecimalError;
+
+    /// Fails with [DecimalError::Overflow] if the value does not fit in 96 bits with a scale of at
+    /// most 28.
+    fn try_from(val: Decimal) -> Result<Self, Self::Error> {
+        let val = if val.scale() as u32 > RustDecimal::MAX_SCALE {
+            val.normalize()
+        } else {
+            val
+        };
+        RustDecimal::try_from_i128_with_scale(val.mantissa(), val.scale() as u32)
+            .map_err(|_| DecimalError::Overflow)
+    }
+}
+
+impl Money {
+    /// Creates a new [Money] from a [`rust_decimal::Decimal`] amount in major units.
+    ///
+    /// Without `rounding`, fails with [DecimalError::Precision] if the value has more decimal
+    /// places than the minor units of the currency, e.g. `1.234` USD.
+    pub fn from_rust_decimal(
+        currency: Currency,
+        value: RustDecimal,
+        rounding: Option<Rounding>,
+    ) -> Result<Self, DecimalError> {
+        Self::from_decimal_with(currency, value.into(), rounding)
+    }
+
+    /// Gets the amount as a [`rust_decimal::Decimal`] in major units.
+    pub fn to_rust_decimal(&self) -> Result<RustDecimal, DecimalError> {
+        self.to_decimal().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rd(s: &str) -> RustDecimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_rust_decimal() {
+        assert_eq!(
+            Money::from_rust_decimal(Currency::USD, rd("1.23"), None),
+            Ok(Money::from_minor(Currency::USD, 123))
+        );
+        assert_eq!(
+            Money::from_rust_decimal(Currency::USD, rd("1.2300"), None),
+            Ok(Money::from_minor(Currency::USD, 123))
+        );
+        assert_eq!(
+            Money::from_rust_decimal(Currency::USD, rd("1.234"), None),
+            Err(DecimalError::Precision)
+        );
+        assert_eq!(
+            Money::from_rust_decimal(Currency::USD, rd("1.235"), Some(Rounding::HalfEven)),
+            Ok(Money::from_minor(Currency::USD, 124))
+        );
+        assert_eq!(
+            Money::from_rust_decimal(Currency::JPY, rd("-1500"), None),
+            Ok(Money::from_minor(Currency::JPY, -1500))
+        );
+        assert_eq!(
+            Money::from_rust_decimal(Currency::KWD, rd("1.234"), None),
+            Ok(Money::from_minor(Currency::KWD, 1234))
+        );
+    }
+
+    #[test]
+    fn test_to_rust_decimal() {
+        assert_eq!(
+            Money::from_minor(Currency::USD, 12345).to_rust_decimal(),
+            Ok(rd("123.45"))
+        );
+        assert_eq!(
+            RustDecimal::try_from(Decimal::new(15, 38).unwrap()),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            RustDecimal::try_from(Decimal::new(1_500_000_000_000, 38).unwrap()),
+            Ok(rd("0.000000000000000000000000015"))
+        );
+        assert_eq!(
+            Money::from_minor(Currency::USD, i128::MAX).to_rust_decimal(),
+            Err(DecimalError::Overflow)
+        );
+    }
+}
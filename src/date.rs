@@ -0,0 +1,123 @@
+//! Calendar dates used for as-of exchange rates and value dates.
+
+use crate::std::{fmt, str::FromStr};
+
+/// A proleptic Gregorian calendar date.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new [Date], returning `None` if the date does not exist.
+    pub const fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            None
+        } else {
+            Some(Self { year, month, day })
+        }
+    }
+
+    /// Gets the year.
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Gets the month (1-12).
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Gets the day of the month (1-31).
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// Error returned when parsing a [Date] fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseDateError;
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDateError {}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    /// Parses an ISO 8601 calendar date, e.g. `2024-01-15`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let b = s.as_bytes();
+        if b.len() != 10 || b[4] != b'-' || b[7] != b'-' {
+            return Err(ParseDateError);
+        }
+
+        let year = parse_digits(&b[..4]).ok_or(ParseDateError)?;
+        let month = parse_digits(&b[5..7]).ok_or(ParseDateError)?;
+        let day = parse_digits(&b[8..]).ok_or(ParseDateError)?;
+
+        Self::new(year, month as u8, day as u8).ok_or(ParseDateError)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Parses a fixed-width run of ASCII digits.
+pub(crate) fn parse_digits(b: &[u8]) -> Option<u16> {
+    b.iter().try_fold(0u16, |acc, &d| {
+        if d.is_ascii_digit() {
+            acc.checked_mul(10)?.checked_add((d - b'0') as u16)
+        } else {
+            None
+        }
+    })
+}
+
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+const fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_date() {
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert!(Date::new(2024, 4, 31).is_none());
+        assert!(Date::new(2024, 13, 1).is_none());
+
+        let date: Date = "2024-01-15".parse().unwrap();
+        assert_eq!(date, Date::new(2024, 1, 15).unwrap());
+        assert_eq!(date.to_string(), "2024-01-15");
+        assert!(Date::new(2024, 1, 14).unwrap() < date);
+
+        assert_eq!("2024-1-15".parse::<Date>(), Err(ParseDateError));
+        assert_eq!("2024-02-30".parse::<Date>(), Err(ParseDateError));
+        assert_eq!("2024/01/15".parse::<Date>(), Err(ParseDateError));
+    }
+}
@@ -0,0 +1,584 @@
+//! Fixed-point decimal numbers for exact money arithmetic.
+//!
+//! A [Decimal] is an `i128` mantissa with a decimal scale, so `123.45` is stored as `12345`
+//! with a scale of `2`. Unlike `f64`, every decimal string within range is represented exactly.
+//!
+//! ```
+//! use currency_iso4217::{Currency, Decimal, Rounding};
+//!
+//! let price: Decimal = "19.995".parse().unwrap();
+//! let total = price.checked_mul(Decimal::from(3)).unwrap();
+//!
+//! assert_eq!(total.to_string(), "59.985");
+//! assert_eq!(total.display_for(Currency::USD).to_string(), "59.98");
+//! assert_eq!(total.round_for(Currency::USD, Rounding::HalfUp).unwrap().to_string(), "59.99");
+//! assert_eq!(total.display_for(Currency::KWD).to_string(), "59.985");
+//! ```
+
+use crate::std::cmp::Ordering;
+use crate::std::hash::{Hash, Hasher};
+use crate::std::{fmt, ops, str::FromStr};
+use crate::{CurrencyUnit, Rounding};
+
+/// Errors returned by [Decimal] parsing and arithmetic.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DecimalError {
+    /// The string is not a plain decimal number, e.g. `-123.45`.
+    Parse,
+    /// The value or scale does not fit in a [Decimal].
+    Overflow,
+    /// The value has more decimal places than allowed, and no rounding was requested.
+    Precision,
+}
+
+impl fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "invalid decimal number"),
+            Self::Overflow => write!(f, "decimal overflow"),
+            Self::Precision => write!(f, "too many decimal places"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecimalError {}
+
+/// A fixed-point decimal number: `mantissa * 10^-scale`.
+///
+/// Equality, ordering and hashing compare numeric values, so `1.5 == 1.50`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u8,
+}
+
+impl Decimal {
+    /// The largest supported scale, the number of digits of `i128::MAX`.
+    pub const MAX_SCALE: u8 = 38;
+
+    /// Zero.
+    pub const ZERO: Self = Self {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// One.
+    pub const ONE: Self = Self {
+        mantissa: 1,
+        scale: 0,
+    };
+
+    /// Creates a new [Decimal] with the value `mantissa * 10^-scale`.
+    ///
+    /// Returns `None` if `scale` is larger than [MAX_SCALE](Self::MAX_SCALE).
+    pub const fn new(mantissa: i128, scale: u8) -> Option<Self> {
+        if scale > Self::MAX_SCALE {
+            None
+        } else {
+            Some(Self { mantissa, scale })
+        }
+    }
+
+    /// Creates a new [Decimal] from parts known to be in range.
+    pub(crate) const fn from_parts(mantissa: i128, scale: u8) -> Self {
+        Self { mantissa, scale }
+    }
+
+    /// Gets the mantissa.
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Gets the scale, the number of digits after the decimal point.
+    pub const fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Gets whether the value is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Gets whether the value is negative.
+    pub const fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Gets the absolute value, or `None` on overflow.
+    pub const fn checked_abs(&self) -> Option<Self> {
+        match self.mantissa.checked_abs() {
+            Some(mantissa) => Some(Self {
+                mantissa,
+                scale: self.scale,
+            }),
+            None => None,
+        }
+    }
+
+    /// Gets the negated value, or `None` on overflow.
+    ///
+    /// Only overflows for a mantissa of [i128::MIN].
+    pub const fn checked_neg(&self) -> Option<Self> {
+        match self.mantissa.checked_neg() {
+            Some(mantissa) => Some(Self {
+                mantissa,
+                scale: self.scale,
+            }),
+            None => None,
+        }
+    }
+
+    /// Gets the same value with trailing fractional zeros removed, e.g. `1.50` to `1.5`.
+    pub const fn normalize(&self) -> Self {
+        let mut mantissa = self.mantissa;
+        let mut scale = self.scale;
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Self { mantissa, scale }
+    }
+
+    /// Changes the scale, rounding with `rounding` if digits are dropped.
+    ///
+    /// Returns `None` on overflow.
+    pub fn rescale(&self, scale: u8, rounding: Rounding) -> Option<Self> {
+        if scale > Self::MAX_SCALE {
+            return None;
+        }
+
+        let mantissa = match scale.cmp(&self.scale) {
+            Ordering::Equal => self.mantissa,
+            Ordering::Greater => self.mantissa.checked_mul(pow10(scale - self.scale)?)?,
+            Ordering::Less => rounding.div(self.mantissa, pow10(self.scale - scale)?)?,
+        };
+
+        Some(Self { mantissa, scale })
+    }
+
+    /// Changes the scale, failing with [DecimalError::Precision] if non-zero digits would be
+    /// dropped.
+    pub fn rescale_exact(&self, scale: u8) -> Result<Self, DecimalError> {
+        let normal = self.normalize();
+        if normal.scale > scale {
+            Err(DecimalError::Precision)
+        } else {
+            normal
+                .rescale(scale, Rounding::Down)
+                .ok_or(DecimalError::Overflow)
+        }
+    }
+
+    /// Rounds to the minor units of `currency`.
+    ///
+    /// Returns `None` on overflow.
+    pub fn round_for<C: CurrencyUnit>(&self, currency: C, rounding: Rounding) -> Option<Self> {
+        self.rescale(currency.exponent(), rounding)
+    }
+
+    /// Gets a value that displays with exactly the minor units of `currency`, rounding half to
+    /// even if needed.
+    pub fn display_for<C: CurrencyUnit>(&self, currency: C) -> DisplayFor {
+        DisplayFor {
+            value: *self,
+            exponent: currency.exponent(),
+        }
+    }
+
+    /// Adds two decimals, or `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b, scale) = self.align(rhs)?;
+        Some(Self {
+            mantissa: a.checked_add(b)?,
+            scale,
+        })
+    }
+
+    /// Subtracts two decimals, or `None` on overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (a, b, scale) = self.align(rhs)?;
+        Some(Self {
+            mantissa: a.checked_sub(b)?,
+            scale,
+        })
+    }
+
+    /// Multiplies two decimals exactly, or `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let mantissa = self.mantissa.checked_mul(rhs.mantissa)?;
+        let scale = self.scale + rhs.scale;
+        if scale <= Self::MAX_SCALE {
+            Some(Self { mantissa, scale })
+        } else {
+            // drop trailing zeros to fit the scale, without losing precision
+            let value = Self { mantissa, scale }.normalize();
+            (value.scale <= Self::MAX_SCALE).then_some(value)
+        }
+    }
+
+    /// Divides two decimals, rounding the quotient to `scale` digits.
+    ///
+    /// Returns `None` when dividing by zero, or on overflow.
+    pub fn checked_div(self, rhs: Self, scale: u8, rounding: Rounding) -> Option<Self> {
+        if rhs.is_zero() || scale > Self::MAX_SCALE {
+            return None;
+        }
+        // (a / 10^sa) / (b / 10^sb) = (a * 10^(scale + sb - sa) / b) / 10^scale
+        let shift = scale as i32 + rhs.scale as i32 - self.scale as i32;
+        let (num, den) = if shift >= 0 {
+            (
+                self.mantissa.checked_mul(pow10(shift as u8)?)?,
+                rhs.mantissa,
+            )
+        } else {
+            (
+                self.mantissa,
+                rhs.mantissa.checked_mul(pow10((-shift) as u8)?)?,
+            )
+        };
+
+        Some(Self {
+            mantissa: rounding.div(num, den)?,
+            scale,
+        })
+    }
+
+    /// Splits into the integer part and the fractional part, both with the sign of the value.
+    fn split(&self) -> (i128, Self) {
+        let unit = 10i128.pow(self.scale as u32);
+        let fraction = Self {
+            mantissa: self.mantissa % unit,
+            scale: self.scale,
+        };
+        (self.mantissa / unit, fraction)
+    }
+
+    /// Brings both mantissas to the larger of the two scales.
+    fn align(self, rhs: Self) -> Option<(i128, i128, u8)> {
+        let scale = self.scale.max(rhs.scale);
+        let a = self.mantissa.checked_mul(pow10(scale - self.scale)?)?;
+        let b = rhs.mantissa.checked_mul(pow10(scale - rhs.scale)?)?;
+        Some((a, b, scale))
+    }
+}
+
+fn pow10(exp: u8) -> Option<i128> {
+    10i128.checked_pow(exp as u32)
+}
+
+impl From<i128> for Decimal {
+    fn from(val: i128) -> Self {
+        Self {
+            mantissa: val,
+            scale: 0,
+        }
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(val: i64) -> Self {
+        (val as i128).into()
+    }
+}
+
+impl From<i32> for Decimal {
+    fn from(val: i32) -> Self {
+        (val as i128).into()
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(val: u64) -> Self {
+        (val as i128).into()
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = DecimalError;
+
+    /// Parses a plain decimal number, e.g. `-123.45`, exactly.
+    ///
+    /// Exponents, digit grouping and surrounding whitespace are not accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+
+        if (int.is_empty() && frac.is_empty())
+            || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return Err(DecimalError::Parse);
+        }
+        if frac.len() > Self::MAX_SCALE as usize {
+            return Err(DecimalError::Overflow);
+        }
+
+        let mut mantissa = 0i128;
+        for b in int.bytes().chain(frac.bytes()) {
+            let digit = (b - b'0') as i128;
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| {
+                    if negative {
+                        m.checked_sub(digit)
+                    } else {
+                        m.checked_add(digit)
+                    }
+                })
+                .ok_or(DecimalError::Overflow)?;
+        }
+
+        Ok(Self {
+            mantissa,
+            scale: frac.len() as u8,
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let abs = self.mantissa.unsigned_abs();
+
+        if self.scale == 0 {
+            write!(f, "{sign}{abs}")
+        } else {
+            let scale = 10u128.pow(self.scale as u32);
+            write!(
+                f,
+                "{sign}{}.{:0width$}",
+                abs / scale,
+                abs % scale,
+                width = self.scale as usize
+            )
+        }
+    }
+}
+
+/// Displays a [Decimal] with the minor units of a currency, see [Decimal::display_for].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayFor {
+    value: Decimal,
+    exponent: u8,
+}
+
+impl fmt::Display for DisplayFor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.rescale(self.exponent, Rounding::HalfEven) {
+            Some(value) => value.fmt(f),
+            // padding a huge value with zeros overflows, show it as-is
+            None => self.value.fmt(f),
+        }
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.align(*other) {
+            Some((a, b, _)) => a.cmp(&b),
+            // aligning overflowed: compare the integer parts, then the fractions, which always align
+            None => {
+                let (ia, fa) = self.split();
+                let (ib, fb) = other.split();
+                ia.cmp(&ib).then_with(|| fa.cmp(&fb))
+            }
+        }
+    }
+}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normal = self.normalize();
+        normal.mantissa.hash(state);
+        normal.scale.hash(state);
+    }
+}
+
+impl ops::Neg for Decimal {
+    type Output = Self;
+
+    /// Negates the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mantissa is [i128::MIN], in release builds too. Use
+    /// [Decimal::checked_neg] to handle that case.
+    fn neg(self) -> Self {
+        self.checked_neg().expect("decimal overflow")
+    }
+}
+
+impl ops::Add for Decimal {
+    type Output = Self;
+
+    /// Panics on overflow, see [Decimal::checked_add].
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("decimal overflow")
+    }
+}
+
+impl ops::Sub for Decimal {
+    type Output = Self;
+
+    /// Panics on overflow, see [Decimal::checked_sub].
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("decimal overflow")
+    }
+}
+
+impl ops::Mul for Decimal {
+    type Output = Self;
+
+    /// Panics on overflow, see [Decimal::checked_mul].
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).expect("decimal overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Currency;
+    use alloc::string::ToString;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(dec("123.45"), Decimal::new(12345, 2).unwrap());
+        assert_eq!(dec("-0.05").mantissa(), -5);
+        assert_eq!(dec("+7").scale(), 0);
+        assert_eq!(dec(".5"), dec("0.50"));
+        assert_eq!(dec("1."), Decimal::ONE);
+        assert_eq!(
+            dec("-170141183460469231731687303715884105728").mantissa(),
+            i128::MIN
+        );
+
+        for bad in ["", "-", ".", "1e3", "1,000", " 1", "1.2.3", "--1", "0x10"] {
+            assert_eq!(bad.parse::<Decimal>(), Err(DecimalError::Parse), "{bad}");
+        }
+        assert_eq!(
+            "170141183460469231731687303715884105728".parse::<Decimal>(),
+            Err(DecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(dec("123.45").to_string(), "123.45");
+        assert_eq!(dec("-0.05").to_string(), "-0.05");
+        assert_eq!(dec("1.50").to_string(), "1.50");
+        assert_eq!(dec("1.50").normalize().to_string(), "1.5");
+
+        assert_eq!(dec("1.5").display_for(Currency::USD).to_string(), "1.50");
+        assert_eq!(dec("1.5").display_for(Currency::JPY).to_string(), "2");
+        assert_eq!(dec("2.5").display_for(Currency::JPY).to_string(), "2");
+        assert_eq!(dec("1.5").display_for(Currency::KWD).to_string(), "1.500");
+        assert_eq!(
+            dec("1.23456").display_for(Currency::CLF).to_string(),
+            "1.2346"
+        );
+        assert_eq!(dec("12").display_for(Currency::XAU).to_string(), "12");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(dec("0.1") + dec("0.2"), dec("0.3"));
+        assert_eq!(dec("1") - dec("0.01"), dec("0.99"));
+        assert_eq!(dec("1.5") * dec("-1.5"), dec("-2.25"));
+        assert_eq!(-dec("1.5"), dec("-1.5"));
+        assert_eq!(
+            dec("10").checked_div(dec("3"), 2, Rounding::HalfEven),
+            Some(dec("3.33"))
+        );
+        assert_eq!(
+            dec("2").checked_div(dec("0.3"), 0, Rounding::Up),
+            Some(dec("7"))
+        );
+        assert_eq!(dec("1").checked_div(Decimal::ZERO, 2, Rounding::Up), None);
+        assert_eq!(Decimal::from(i128::MAX).checked_add(Decimal::ONE), None);
+        assert_eq!(
+            Decimal::from(i128::MAX).checked_add(dec("0.1")),
+            None,
+            "aligning scales overflows"
+        );
+    }
+
+    #[test]
+    fn test_rescale() {
+        assert_eq!(
+            dec("1.005").rescale(2, Rounding::HalfEven),
+            Some(Decimal::new(100, 2).unwrap())
+        );
+        assert_eq!(
+            dec("1.005").rescale(2, Rounding::HalfUp),
+            Some(Decimal::new(101, 2).unwrap())
+        );
+        assert_eq!(
+            dec("1.5").rescale(3, Rounding::Down).unwrap().mantissa(),
+            1500
+        );
+        assert_eq!(dec("1.500").rescale_exact(2), Ok(dec("1.5")));
+        assert_eq!(dec("1.234").rescale_exact(2), Err(DecimalError::Precision));
+        assert_eq!(
+            dec("-1.235").round_for(Currency::USD, Rounding::HalfEven),
+            Some(dec("-1.24"))
+        );
+        assert_eq!(
+            dec("1.5").round_for(Currency::XAU, Rounding::Floor),
+            Some(Decimal::ONE)
+        );
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(-dec("1.50"), dec("-1.5"));
+        assert_eq!(dec("-0.01").checked_neg(), Some(dec("0.01")));
+
+        let min = dec("-170141183460469231731687303715884105728");
+        assert_eq!(min.mantissa(), i128::MIN);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(min.checked_abs(), None);
+        assert_eq!(
+            dec("-1701411834604692317316873037158841057.28").checked_neg(),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal overflow")]
+    fn test_neg_overflow() {
+        let _ = -Decimal::from(i128::MIN);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert_eq!(dec("1.5"), dec("1.50"));
+        assert!(dec("1.49") < dec("1.5"));
+        assert!(dec("-2") < dec("-1.99"));
+        assert!(Decimal::from(i128::MAX) > dec("1.5"));
+        assert!(Decimal::from(i128::MIN) < dec("-1.5"));
+        assert!(Decimal::new(i128::MAX, 0).unwrap() > Decimal::new(i128::MAX, 1).unwrap());
+    }
+}
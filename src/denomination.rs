@@ -0,0 +1,690 @@
+//! Banknote and coin denominations, for cash handling devices.
+//!
+//! Values are in minor units of the currency, e.g. `25` for the USD quarter. Withdrawn
+//! denominations that are still commonly handled are listed with `in_circulation` unset.
+//!
+//! Denomination data covers the currencies most handled by cash devices: AED, AUD, BRL, CAD,
+//! CHF, CNY, CZK, DKK, EUR, GBP, HKD, HUF, IDR, ILS, INR, JPY, KRW, KWD, MXN, MYR, NOK, NZD, PHP,
+//! PLN, SAR, SEK, SGD, THB, TRY, TWD, USD and ZAR. Currencies without banknotes or coins (funds,
+//! precious metals and units of account) have no denominations, and all other currencies have
+//! no data.
+//!
+//! ```
+//! use currency_iso4217::Currency;
+//!
+//! assert!(Currency::USD.notes().eq([100, 200, 500, 1000, 2000, 5000, 10000]));
+//! assert!(Currency::USD.coins().eq([1, 5, 10, 25, 50, 100]));
+//! assert_eq!(Currency::XAU.denominations(), Some(&[][..]));
+//! assert_eq!(Currency::BTN.denominations(), None);
+//! ```
+
+use crate::Currency;
+
+/// Whether a denomination is a banknote or a coin.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DenominationKind {
+    /// A coin.
+    Coin,
+    /// A banknote.
+    Note,
+}
+
+/// A banknote or coin denomination of a currency.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Denomination {
+    /// Face value in minor units of the currency.
+    pub value: u32,
+    /// Banknote or coin.
+    pub kind: DenominationKind,
+    /// Whether the denomination is currently issued.
+    pub in_circulation: bool,
+}
+
+impl Denomination {
+    const fn coin(value: u32) -> Self {
+        Self {
+            value,
+            kind: DenominationKind::Coin,
+            in_circulation: true,
+        }
+    }
+
+    const fn note(value: u32) -> Self {
+        Self {
+            value,
+            kind: DenominationKind::Note,
+            in_circulation: true,
+        }
+    }
+
+    const fn withdrawn(self) -> Self {
+        Self {
+            in_circulation: false,
+            ..self
+        }
+    }
+
+    /// Gets whether the denomination is a banknote.
+    pub const fn is_note(&self) -> bool {
+        matches!(self.kind, DenominationKind::Note)
+    }
+
+    /// Gets whether the denomination is a coin.
+    pub const fn is_coin(&self) -> bool {
+        matches!(self.kind, DenominationKind::Coin)
+    }
+}
+
+use Denomination as D;
+
+const AED: &[D] = &[
+    D::coin(25),
+    D::coin(50),
+    D::coin(100),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(100000),
+];
+
+const AUD: &[D] = &[
+    D::coin(1).withdrawn(),
+    D::coin(2).withdrawn(),
+    D::coin(5),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+];
+
+const BRL: &[D] = &[
+    D::coin(1).withdrawn(),
+    D::coin(5),
+    D::coin(10),
+    D::coin(25),
+    D::coin(50),
+    D::coin(100),
+    D::note(200),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+];
+
+const CAD: &[D] = &[
+    D::coin(1).withdrawn(),
+    D::coin(5),
+    D::coin(10),
+    D::coin(25),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+];
+
+const CHF: &[D] = &[
+    D::coin(1).withdrawn(),
+    D::coin(5),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(100000),
+];
+
+const CNY: &[D] = &[
+    D::coin(10),
+    D::coin(50),
+    D::coin(100),
+    D::note(100),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+];
+
+const CZK: &[D] = &[
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::coin(1000),
+    D::coin(2000),
+    D::coin(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(100000),
+    D::note(200000),
+    D::note(500000),
+];
+
+const DKK: &[D] = &[
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::coin(1000),
+    D::coin(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(100000),
+];
+
+const EUR: &[D] = &[
+    D::coin(1),
+    D::coin(2),
+    D::coin(5),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000).withdrawn(),
+];
+
+const GBP: &[D] = &[
+    D::coin(1),
+    D::coin(2),
+    D::coin(5),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+];
+
+const HKD: &[D] = &[
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::coin(1000),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(50000),
+    D::note(100000),
+];
+
+const HUF: &[D] = &[
+    D::coin(500),
+    D::coin(1000),
+    D::coin(2000),
+    D::coin(5000),
+    D::coin(10000),
+    D::coin(20000),
+    D::note(50000),
+    D::note(100000),
+    D::note(200000),
+    D::note(500000),
+    D::note(1000000),
+    D::note(2000000),
+];
+
+const IDR: &[D] = &[
+    D::coin(10000),
+    D::coin(20000),
+    D::coin(50000),
+    D::coin(100000),
+    D::note(100000),
+    D::note(200000),
+    D::note(500000),
+    D::note(1000000),
+    D::note(2000000),
+    D::note(5000000),
+    D::note(10000000),
+];
+
+const ILS: &[D] = &[
+    D::coin(10),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::coin(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+];
+
+const INR: &[D] = &[
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::coin(1000),
+    D::note(1000),
+    D::coin(2000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(200000).withdrawn(),
+];
+
+const JPY: &[D] = &[
+    D::coin(1),
+    D::coin(5),
+    D::coin(10),
+    D::coin(50),
+    D::coin(100),
+    D::coin(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+];
+
+const KRW: &[D] = &[
+    D::coin(10),
+    D::coin(50),
+    D::coin(100),
+    D::coin(500),
+    D::note(1000),
+    D::note(5000),
+    D::note(10000),
+    D::note(50000),
+];
+
+const KWD: &[D] = &[
+    D::coin(5),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::note(250),
+    D::note(500),
+    D::note(1000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+];
+
+const MXN: &[D] = &[
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::coin(1000),
+    D::coin(2000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(100000),
+];
+
+const MYR: &[D] = &[
+    D::coin(5),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::note(100),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+];
+
+const NOK: &[D] = &[
+    D::coin(100),
+    D::coin(500),
+    D::coin(1000),
+    D::coin(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(100000),
+];
+
+const NZD: &[D] = &[
+    D::coin(5).withdrawn(),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+];
+
+const PHP: &[D] = &[
+    D::coin(1),
+    D::coin(5),
+    D::coin(25),
+    D::coin(100),
+    D::coin(500),
+    D::coin(1000),
+    D::coin(2000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(100000),
+];
+
+const PLN: &[D] = &[
+    D::coin(1),
+    D::coin(2),
+    D::coin(5),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+];
+
+const SAR: &[D] = &[
+    D::coin(5),
+    D::coin(10),
+    D::coin(25),
+    D::coin(50),
+    D::coin(100),
+    D::note(100),
+    D::coin(200),
+    D::note(500),
+    D::note(1000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+];
+
+const SEK: &[D] = &[
+    D::coin(50).withdrawn(),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::coin(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(100000),
+];
+
+const SGD: &[D] = &[
+    D::coin(5),
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::note(200),
+    D::note(500),
+    D::note(1000),
+    D::note(5000),
+    D::note(10000),
+    D::note(100000),
+    D::note(1000000).withdrawn(),
+];
+
+const THB: &[D] = &[
+    D::coin(25),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::coin(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(50000),
+    D::note(100000),
+];
+
+const TRY: &[D] = &[
+    D::coin(1),
+    D::coin(5),
+    D::coin(10),
+    D::coin(25),
+    D::coin(50),
+    D::coin(100),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+];
+
+const TWD: &[D] = &[
+    D::coin(100),
+    D::coin(500),
+    D::coin(1000),
+    D::coin(2000),
+    D::coin(5000),
+    D::note(10000),
+    D::note(20000),
+    D::note(50000),
+    D::note(100000),
+    D::note(200000),
+];
+
+const USD: &[D] = &[
+    D::coin(1),
+    D::coin(5),
+    D::coin(10),
+    D::coin(25),
+    D::coin(50),
+    D::coin(100),
+    D::note(100),
+    D::note(200),
+    D::note(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+];
+
+const ZAR: &[D] = &[
+    D::coin(10),
+    D::coin(20),
+    D::coin(50),
+    D::coin(100),
+    D::coin(200),
+    D::coin(500),
+    D::note(1000),
+    D::note(2000),
+    D::note(5000),
+    D::note(10000),
+    D::note(20000),
+];
+
+impl Currency {
+    /// Gets the banknote and coin denominations, including withdrawn ones, in ascending order of
+    /// value.
+    ///
+    /// Empty for currencies without banknotes or coins, e.g. funds and precious metals, and `None`
+    /// for currencies without denomination data, see the [module documentation](self).
+    pub const fn denominations(&self) -> Option<&'static [Denomination]> {
+        match self {
+            Self::AED => Some(AED),
+            Self::AUD => Some(AUD),
+            Self::BRL => Some(BRL),
+            Self::CAD => Some(CAD),
+            Self::CHF => Some(CHF),
+            Self::CNY => Some(CNY),
+            Self::CZK => Some(CZK),
+            Self::DKK => Some(DKK),
+            Self::EUR => Some(EUR),
+            Self::GBP => Some(GBP),
+            Self::HKD => Some(HKD),
+            Self::HUF => Some(HUF),
+            Self::IDR => Some(IDR),
+            Self::ILS => Some(ILS),
+            Self::INR => Some(INR),
+            Self::JPY => Some(JPY),
+            Self::KRW => Some(KRW),
+            Self::KWD => Some(KWD),
+            Self::MXN => Some(MXN),
+            Self::MYR => Some(MYR),
+            Self::NOK => Some(NOK),
+            Self::NZD => Some(NZD),
+            Self::PHP => Some(PHP),
+            Self::PLN => Some(PLN),
+            Self::SAR => Some(SAR),
+            Self::SEK => Some(SEK),
+            Self::SGD => Some(SGD),
+            Self::THB => Some(THB),
+            Self::TRY => Some(TRY),
+            Self::TWD => Some(TWD),
+            Self::USD => Some(USD),
+            Self::ZAR => Some(ZAR),
+            // precious metals, units of account, and funds
+            Self::XAG
+            | Self::XAU
+            | Self::XBA
+            | Self::XBB
+            | Self::XBC
+            | Self::XBD
+            | Self::XDR
+            | Self::XPD
+            | Self::XPT
+            | Self::XSU
+            | Self::XUA => Some(&[]),
+            _ if self.is_fund() => Some(&[]),
+            _ => None,
+        }
+    }
+
+    /// Gets the values of the banknotes in circulation, in minor units, in ascending order.
+    pub fn notes(&self) -> impl Iterator<Item = u32> {
+        self.circulating(DenominationKind::Note)
+    }
+
+    /// Gets the values of the coins in circulation, in minor units, in ascending order.
+    pub fn coins(&self) -> impl Iterator<Item = u32> {
+        self.circulating(DenominationKind::Coin)
+    }
+
+    fn circulating(&self, kind: DenominationKind) -> impl Iterator<Item = u32> {
+        self.denominations()
+            .unwrap_or_default()
+            .iter()
+            .filter(move |d| d.in_circulation && d.kind == kind)
+            .map(|d| d.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denominations() {
+        assert!(Currency::EUR
+            .notes()
+            .eq([500, 1000, 2000, 5000, 10000, 20000]));
+        assert!(Currency::EUR
+            .denominations()
+            .unwrap()
+            .iter()
+            .any(|d| d.value == 50000));
+        assert!(Currency::JPY.coins().eq([1, 5, 10, 50, 100, 500]));
+        assert!(Currency::KWD
+            .notes()
+            .eq([250, 500, 1000, 5000, 10000, 20000]));
+        assert!(Currency::CAD.coins().eq([5, 10, 25, 50, 100, 200]));
+        assert_eq!(Currency::BTN.notes().count(), 0);
+        assert!(Currency::TRY.coins().eq([1, 5, 10, 25, 50, 100]));
+        assert!(Currency::THB.notes().eq([2000, 5000, 10000, 50000, 100000]));
+
+        let usd_dollar: [_; 2] = [D::coin(100), D::note(100)];
+        assert!(Currency::USD
+            .denominations()
+            .unwrap()
+            .iter()
+            .filter(|d| d.value == 100)
+            .eq(usd_dollar.iter()));
+        assert!(usd_dollar[1].is_note() && !usd_dollar[1].is_coin());
+    }
+
+    #[test]
+    fn test_denominations_coverage() {
+        assert_eq!(Currency::BTN.denominations(), None);
+        assert_eq!(Currency::XTS.denominations(), None);
+        for cur in [Currency::XAU, Currency::XDR, Currency::USN, Currency::CHE] {
+            assert_eq!(cur.denominations(), Some(&[][..]), "{}", cur.alpha());
+        }
+
+        let covered = Currency::LIST
+            .iter()
+            .filter(|cur| cur.denominations().is_some_and(|d| !d.is_empty()))
+            .count();
+        assert_eq!(covered, 32);
+    }
+
+    #[test]
+    fn test_denominations_sorted() {
+        for cur in Currency::LIST {
+            let denoms = cur.denominations().unwrap_or_default();
+            assert!(
+                denoms
+                    .windows(2)
+                    .all(|w| (w[0].value, w[0].kind) < (w[1].value, w[1].kind)),
+                "{} denominations out of order",
+                cur.alpha()
+            );
+            assert!(denoms.iter().all(|d| d.value > 0));
+        }
+    }
+}
@@ -0,0 +1,444 @@
+//! Change-making: choosing the banknotes and coins to dispense for an amount.
+//!
+//! The search is exact, so it finds the optimal dispense also for non-canonical denomination
+//! systems and limited inventories, where taking the largest denomination first fails. It runs
+//! without allocation, on the stack, with at most [MAX_DENOMINATIONS] denominations and
+//! [MAX_STEPS] search steps.
+//!
+//! ```
+//! use currency_iso4217::dispense::{dispense, Strategy};
+//! use currency_iso4217::{Currency, Money};
+//!
+//! // no nickels left: 30 cents must be three dimes, not a quarter and a nickel
+//! let inventory = [(25, 10), (10, 10), (5, 0)];
+//! let amount = Money::from_minor(Currency::USD, 30);
+//! let plan = dispense(amount, &inventory, Strategy::FewestPieces).unwrap();
+//! assert_eq!(plan.counts(), [0, 3, 0]);
+//! ```
+
+use crate::std::fmt;
+use crate::{Currency, Money};
+
+/// Largest number of denominations in an inventory.
+pub const MAX_DENOMINATIONS: usize = 32;
+/// Largest number of search steps before giving up with [DispenseError::SearchLimit].
+pub const MAX_STEPS: u32 = 1_000_000;
+
+/// Weight of scarcity in [Strategy::PreserveScarce].
+const SCARCITY: u64 = 1 << 20;
+
+/// What an optimal dispense minimizes.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Strategy {
+    /// The number of pieces.
+    #[default]
+    FewestPieces,
+    /// The use of denominations with few pieces left, then the number of pieces.
+    PreserveScarce,
+}
+
+/// Errors returned when planning a dispense.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DispenseError {
+    /// The amount is negative or too large.
+    InvalidAmount,
+    /// The inventory has more than [MAX_DENOMINATIONS] denominations.
+    TooManyDenominations,
+    /// A denomination has a zero value, or is not a denomination of the currency.
+    InvalidDenomination(u32),
+    /// The amount cannot be made exactly from the inventory.
+    Insufficient,
+    /// The search exceeded [MAX_STEPS] steps.
+    SearchLimit,
+}
+
+impl fmt::Display for DispenseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAmount => write!(f, "invalid amount"),
+            Self::TooManyDenominations => {
+                write!(f, "more than {MAX_DENOMINATIONS} denominations")
+            }
+            Self::InvalidDenomination(value) => write!(f, "invalid denomination {value}"),
+            Self::Insufficient => write!(f, "amount cannot be dispensed from the inventory"),
+            Self::SearchLimit => write!(f, "search limit exceeded"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DispenseError {}
+
+/// A planned dispense: the number of pieces of each denomination, in inventory order.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Dispense {
+    currency: Currency,
+    values: [u32; MAX_DENOMINATIONS],
+    counts: [u32; MAX_DENOMINATIONS],
+    len: usize,
+}
+
+impl Dispense {
+    /// Gets the currency.
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Gets the number of pieces of each denomination, in inventory order.
+    pub fn counts(&self) -> &[u32] {
+        &self.counts[..self.len]
+    }
+
+    /// Gets the denominations and their number of pieces, skipping unused denominations.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.values[..self.len]
+            .iter()
+            .copied()
+            .zip(self.counts().iter().copied())
+            .filter(|&(_, count)| count > 0)
+    }
+
+    /// Gets the total number of pieces.
+    pub fn pieces(&self) -> u64 {
+        self.counts().iter().map(|&c| c as u64).sum()
+    }
+
+    /// Gets the total amount.
+    pub fn total(&self) -> Money {
+        let amount = self.iter().map(|(v, c)| v as i128 * c as i128).sum();
+        Money::from_minor(self.currency, amount)
+    }
+}
+
+/// Plans the optimal dispense of `amount` from an inventory of `(value, count)` pairs, with
+/// values in minor units.
+///
+/// If the currency has denomination data, the values must be denominations of the currency.
+pub fn dispense(
+    amount: Money,
+    inventory: &[(u32, u32)],
+    strategy: Strategy,
+) -> Result<Dispense, DispenseError> {
+    let currency = amount.currency();
+    let known = currency.denominations();
+    if inventory.len() > MAX_DENOMINATIONS {
+        return Err(DispenseError::TooManyDenominations);
+    }
+    if let Some(&(value, _)) = inventory
+        .iter()
+        .find(|&&(v, _)| v == 0 || known.is_some_and(|k| !k.iter().any(|d| d.value == v)))
+    {
+        return Err(DispenseError::InvalidDenomination(value));
+    }
+    let amount = u64::try_from(amount.amount()).map_err(|_| DispenseError::InvalidAmount)?;
+
+    let mut search = Search::new(inventory, strategy);
+    search.run(amount)?;
+
+    let mut plan = Dispense {
+        currency,
+        values: [0; MAX_DENOMINATIONS],
+        counts: [0; MAX_DENOMINATIONS],
+        len: inventory.len(),
+    };
+    for (i, &(value, _)) in inventory.iter().enumerate() {
+        plan.values[i] = value;
+    }
+    for (pos, &i) in search.order[..search.len].iter().enumerate() {
+        plan.counts[i] = search.best[pos] as u32;
+    }
+    Ok(plan)
+}
+
+/// Plans the dispense of `amount` with the fewest pieces, from an unlimited supply of the
+/// denominations of the currency in circulation.
+pub fn make_change(amount: Money) -> Result<Dispense, DispenseError> {
+    let mut inventory = [(0, u32::MAX); MAX_DENOMINATIONS];
+    let mut len = 0;
+    for d in amount.currency().denominations().unwrap_or_default() {
+        if d.in_circulation && !inventory[..len].iter().any(|&(v, _)| v == d.value) {
+            inventory[len].0 = d.value;
+            len += 1;
+        }
+    }
+    dispense(amount, &inventory[..len], Strategy::FewestPieces)
+}
+
+/// Branch and bound search over the counts of each denomination, largest first.
+struct Search {
+    /// Inventory indices, by descending value.
+    order: [usize; MAX_DENOMINATIONS],
+    values: [u64; MAX_DENOMINATIONS],
+    available: [u64; MAX_DENOMINATIONS],
+    weights: [u64; MAX_DENOMINATIONS],
+    /// Largest amount that the denominations from each position can make.
+    reach: [u64; MAX_DENOMINATIONS],
+    /// Greatest common divisor of the denominations from each position.
+    gcd: [u64; MAX_DENOMINATIONS],
+    /// Position of the cheapest denomination per unit of value from each position.
+    cheapest: [usize; MAX_DENOMINATIONS],
+    len: usize,
+    current: [u64; MAX_DENOMINATIONS],
+    best: [u64; MAX_DENOMINATIONS],
+    best_cost: u64,
+    steps: u32,
+}
+
+impl Search {
+    fn new(inventory: &[(u32, u32)], strategy: Strategy) -> Self {
+        let len = inventory.len();
+        let mut order = [0; MAX_DENOMINATIONS];
+        for (i, slot) in order[..len].iter_mut().enumerate() {
+            *slot = i;
+        }
+        order[..len].sort_unstable_by(|&a, &b| inventory[b].0.cmp(&inventory[a].0));
+
+        let mut search = Self {
+            order,
+            values: [0; MAX_DENOMINATIONS],
+            available: [0; MAX_DENOMINATIONS],
+            weights: [0; MAX_DENOMINATIONS],
+            reach: [0; MAX_DENOMINATIONS],
+            gcd: [0; MAX_DENOMINATIONS],
+            cheapest: [0; MAX_DENOMINATIONS],
+            len,
+            current: [0; MAX_DENOMINATIONS],
+            best: [0; MAX_DENOMINATIONS],
+            best_cost: u64::MAX,
+            steps: 0,
+        };
+
+        for pos in 0..len {
+            let (value, count) = inventory[order[pos]];
+            search.values[pos] = value as u64;
+            search.available[pos] = count as u64;
+            search.weights[pos] = match strategy {
+                Strategy::FewestPieces => 1,
+                Strategy::PreserveScarce => 1 + SCARCITY / (count as u64).max(1),
+            };
+        }
+
+        for pos in (0..len).rev() {
+            let (value, count) = (search.values[pos], search.available[pos]);
+            let (reach, gcd, cheapest) = if pos + 1 < len {
+                (
+                    search.reach[pos + 1],
+                    search.gcd[pos + 1],
+                    search.cheapest[pos + 1],
+                )
+            } else {
+                (0, 0, pos)
+            };
+            let usable = count > 0;
+            search.reach[pos] = reach.saturating_add(value.saturating_mul(count));
+            search.gcd[pos] = if usable { gcd_u64(gcd, value) } else { gcd };
+            // compare weight / value ratios without division
+            search.cheapest[pos] = if usable
+                && (pos + 1 == len
+                    || search.available[cheapest] == 0
+                    || (search.weights[pos] as u128 * search.values[cheapest] as u128)
+                        < (search.weights[cheapest] as u128 * value as u128))
+            {
+                pos
+            } else {
+                cheapest
+            };
+        }
+
+        search
+    }
+
+    fn run(&mut self, amount: u64) -> Result<(), DispenseError> {
+        self.visit(0, amount, 0)?;
+        if self.best_cost == u64::MAX {
+            Err(DispenseError::Insufficient)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn visit(&mut self, pos: usize, remaining: u64, cost: u64) -> Result<(), DispenseError> {
+        if remaining == 0 {
+            if cost < self.best_cost {
+                self.best_cost = cost;
+                self.best = self.current;
+            }
+            return Ok(());
+        }
+        if pos == self.len
+            || self.reach[pos] < remaining
+            || self.gcd[pos] == 0
+            || remaining % self.gcd[pos] != 0
+        {
+            return Ok(());
+        }
+
+        // every remaining unit of value costs at least the cheapest ratio
+        let cheapest = self.cheapest[pos];
+        let bound = (remaining as u128 * self.weights[cheapest] as u128)
+            .div_ceil(self.values[cheapest] as u128);
+        if cost as u128 + bound >= self.best_cost as u128 {
+            return Ok(());
+        }
+
+        let value = self.values[pos];
+        let rest = if pos + 1 < self.len {
+            self.reach[pos + 1]
+        } else {
+            0
+        };
+        // fewer pieces leave more than the smaller denominations can make
+        let min = remaining.saturating_sub(rest).div_ceil(value);
+        let max = self.available[pos].min(remaining / value);
+        for count in (min..=max).rev() {
+            self.steps += 1;
+            if self.steps > MAX_STEPS {
+                return Err(DispenseError::SearchLimit);
+            }
+
+            self.current[pos] = count;
+            let remaining = remaining - count * value;
+            let cost = cost.saturating_add(count.saturating_mul(self.weights[pos]));
+
+            // if this denomination is the cheapest, the bound only grows with fewer pieces
+            if cheapest == pos && remaining > 0 && pos + 1 < self.len {
+                let next = self.cheapest[pos + 1];
+                let bound = (remaining as u128 * self.weights[next] as u128)
+                    .div_ceil(self.values[next] as u128);
+                if cost as u128 + bound >= self.best_cost as u128 {
+                    break;
+                }
+            }
+            self.visit(pos + 1, remaining, cost)?;
+        }
+        self.current[pos] = 0;
+        Ok(())
+    }
+}
+
+const fn gcd_u64(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd_u64(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd(amount: i128) -> Money {
+        Money::from_minor(Currency::USD, amount)
+    }
+
+    #[test]
+    fn test_make_change() {
+        let plan = make_change(usd(1_87)).unwrap();
+        // $1 coin, half dollar, quarter, dime, two pennies
+        assert_eq!(plan.pieces(), 6);
+        assert_eq!(plan.total(), usd(1_87));
+
+        let plan = make_change(Money::from_minor(Currency::JPY, 3_780)).unwrap();
+        // denominations in ascending order, as listed for the currency
+        assert!(plan
+            .iter()
+            .eq([(10, 3), (50, 1), (100, 2), (500, 1), (1000, 1), (2000, 1)]));
+        assert_eq!(plan.pieces(), 2 + 1 + 2 + 1 + 3);
+        assert_eq!(make_change(usd(0)).unwrap().pieces(), 0);
+        assert_eq!(
+            make_change(Money::from_minor(Currency::XAU, 1)),
+            Err(DispenseError::Insufficient)
+        );
+    }
+
+    #[test]
+    fn test_non_canonical() {
+        // greedy takes 4 + 1 + 1, the optimum is 3 + 3
+        let inventory = [(1, 10), (3, 10), (4, 10)];
+        let amount = Money::from_minor(Currency::XXX, 6);
+        let plan = dispense(amount, &inventory, Strategy::FewestPieces).unwrap();
+        assert_eq!(plan.counts(), [0, 2, 0]);
+
+        // limited inventory: no nickels, so a quarter cannot be used for 30 cents
+        let inventory = [(25, 5), (10, 5), (5, 0), (1, 0)];
+        let plan = dispense(usd(30), &inventory, Strategy::FewestPieces).unwrap();
+        assert_eq!(plan.counts(), [0, 3, 0, 0]);
+        assert!(plan.iter().eq([(10, 3)]));
+
+        assert_eq!(
+            dispense(usd(31), &inventory, Strategy::FewestPieces),
+            Err(DispenseError::Insufficient)
+        );
+        assert_eq!(
+            dispense(usd(1_000), &inventory, Strategy::FewestPieces),
+            Err(DispenseError::Insufficient)
+        );
+    }
+
+    #[test]
+    fn test_preserve_scarce() {
+        let inventory = [(500, 2), (100, 100)];
+        let plan = dispense(usd(5_00), &inventory, Strategy::FewestPieces).unwrap();
+        assert_eq!(plan.counts(), [1, 0]);
+        let plan = dispense(usd(5_00), &inventory, Strategy::PreserveScarce).unwrap();
+        assert_eq!(plan.counts(), [0, 5]);
+
+        // both denominations plentiful: fewest pieces
+        let inventory = [(500, 100), (100, 100)];
+        let plan = dispense(usd(10_00), &inventory, Strategy::PreserveScarce).unwrap();
+        assert_eq!(plan.counts(), [2, 0]);
+    }
+
+    #[test]
+    fn test_large_amounts() {
+        // pruned counts are skipped, not iterated one by one
+        let plan = make_change(usd(1_000_000_000_000 + 1_87)).unwrap();
+        assert_eq!(plan.total(), usd(1_000_000_000_000 + 1_87));
+        assert_eq!(plan.pieces(), 100_000_000 + 6);
+
+        let inventory = [(10000, u32::MAX), (500, u32::MAX), (1, u32::MAX)];
+        let amount = usd(400_000_000_000);
+        let plan = dispense(amount, &inventory, Strategy::FewestPieces).unwrap();
+        assert_eq!(plan.counts(), [40_000_000, 0, 0]);
+        let plan = dispense(amount, &inventory, Strategy::PreserveScarce).unwrap();
+        assert_eq!(plan.counts(), [40_000_000, 0, 0]);
+
+        // not enough large notes: the rest comes from the smaller ones
+        let inventory = [(10000, 10), (500, u32::MAX), (1, u32::MAX)];
+        let plan = dispense(amount, &inventory, Strategy::FewestPieces).unwrap();
+        assert_eq!(plan.counts(), [10, 799_999_800, 0]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            dispense(usd(-1), &[(100, 1)], Strategy::FewestPieces),
+            Err(DispenseError::InvalidAmount)
+        );
+        assert_eq!(
+            dispense(usd(100), &[(300, 1)], Strategy::FewestPieces),
+            Err(DispenseError::InvalidDenomination(300))
+        );
+        assert_eq!(
+            dispense(
+                Money::from_minor(Currency::XXX, 1),
+                &[(0, 1)],
+                Strategy::FewestPieces
+            ),
+            Err(DispenseError::InvalidDenomination(0))
+        );
+        assert_eq!(
+            dispense(
+                Money::from_minor(Currency::XAU, 1),
+                &[(1, 1)],
+                Strategy::FewestPieces
+            ),
+            Err(DispenseError::InvalidDenomination(1))
+        );
+        assert_eq!(
+            dispense(usd(100), &[(1, 1); 33], Strategy::FewestPieces),
+            Err(DispenseError::TooManyDenominations)
+        );
+    }
+}
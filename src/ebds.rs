@@ -0,0 +1,306 @@
+//! EBDS extended note data, as reported by bill validators in extended note messages.
+//!
+//! The 18-byte block describes one note:
+//!
+//! | Bytes | Field                                                |
+//! |-------|------------------------------------------------------|
+//! | 0     | Index of the note in the validator's note table      |
+//! | 1-3   | ISO 4217 alphabetic code, e.g. `USD`                 |
+//! | 4-6   | Base value, ASCII digits, e.g. `005`                 |
+//! | 7     | Sign of the exponent, `+` or `-`                     |
+//! | 8-9   | Exponent, ASCII digits, e.g. `01`                    |
+//! | 10    | Orientation of the note when it was inserted         |
+//! | 11    | Note type, e.g. `A`                                  |
+//! | 12    | Note series, e.g. `A`                                |
+//! | 13    | Note compatibility, e.g. `B`                         |
+//! | 14    | Note version, e.g. `A`                               |
+//! | 15-17 | Reserved                                             |
+//!
+//! The value of the note is `base * 10^(sign exponent)` major units of the currency.
+//!
+//! ```
+//! use currency_iso4217::ebds::NoteDescriptor;
+//! use currency_iso4217::{Currency, Money};
+//!
+//! let note = NoteDescriptor::parse(b"\x03USD005+01\x00AABA\x00\x00\x00").unwrap();
+//! assert_eq!(note.currency, Currency::USD);
+//! assert_eq!(note.face_value(), Ok(Money::from_minor(Currency::USD, 5000)));
+//! ```
+
+use crate::date::parse_digits;
+use crate::std::fmt;
+use crate::{Currency, Money};
+
+/// Length of an extended note data block.
+pub const NOTE_DATA_LEN: usize = 18;
+
+/// Errors returned when parsing EBDS extended note data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EbdsError {
+    /// The block is not [NOTE_DATA_LEN] bytes.
+    InvalidLength,
+    /// The alphabetic code is not a known currency.
+    UnknownCurrency([u8; 3]),
+    /// The base value or exponent is not ASCII digits.
+    InvalidDigit,
+    /// The exponent sign is not `+` or `-`.
+    InvalidSign(u8),
+    /// The orientation byte is not a known orientation.
+    InvalidOrientation(u8),
+    /// The face value is not a whole number of minor units, or does not fit in [Money].
+    InvalidValue,
+}
+
+impl fmt::Display for EbdsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "note data must be {NOTE_DATA_LEN} bytes"),
+            Self::UnknownCurrency(code) => write!(
+                f,
+                "unknown currency code {}",
+                crate::std::str::from_utf8(code).unwrap_or_default()
+            ),
+            Self::InvalidDigit => write!(f, "invalid digit"),
+            Self::InvalidSign(b) => write!(f, "invalid exponent sign {b:#04x}"),
+            Self::InvalidOrientation(b) => write!(f, "invalid orientation {b:#04x}"),
+            Self::InvalidValue => write!(f, "invalid face value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EbdsError {}
+
+/// Orientation of a note when it was inserted.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(u8)]
+pub enum Orientation {
+    /// Right edge first, face up.
+    RightUp = 0x00,
+    /// Right edge first, face down.
+    RightDown = 0x01,
+    /// Left edge first, face up.
+    LeftUp = 0x02,
+    /// Left edge first, face down.
+    LeftDown = 0x03,
+    /// Not known, e.g. in note table entries.
+    #[default]
+    Unknown = 0xFF,
+}
+
+impl TryFrom<u8> for Orientation {
+    type Error = EbdsError;
+
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0x00 => Ok(Self::RightUp),
+            0x01 => Ok(Self::RightDown),
+            0x02 => Ok(Self::LeftUp),
+            0x03 => Ok(Self::LeftDown),
+            0xFF => Ok(Self::Unknown),
+            _ => Err(EbdsError::InvalidOrientation(val)),
+        }
+    }
+}
+
+/// A note described by EBDS extended note data.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NoteDescriptor {
+    /// Index of the note in the validator's note table.
+    pub index: u8,
+    /// Currency of the note.
+    pub currency: Currency,
+    /// Base value, `0` to `999`.
+    pub base_value: u16,
+    /// Exponent of ten applied to the base value, `-99` to `99`.
+    pub exponent: i8,
+    /// Orientation of the note when it was inserted.
+    pub orientation: Orientation,
+    /// Note type, an ASCII letter.
+    pub note_type: u8,
+    /// Note series, an ASCII letter.
+    pub series: u8,
+    /// Note compatibility, an ASCII letter.
+    pub compatibility: u8,
+    /// Note version, an ASCII letter.
+    pub version: u8,
+}
+
+impl NoteDescriptor {
+    /// Parses an extended note data block.
+    pub fn parse(data: &[u8]) -> Result<Self, EbdsError> {
+        let data: &[u8; NOTE_DATA_LEN] = data.try_into().map_err(|_| EbdsError::InvalidLength)?;
+
+        let code = [data[1], data[2], data[3]];
+        let currency = Currency::from_alpha(&code).ok_or(EbdsError::UnknownCurrency(code))?;
+        let base_value = parse_digits(&data[4..7]).ok_or(EbdsError::InvalidDigit)?;
+        let exponent = parse_digits(&data[8..10]).ok_or(EbdsError::InvalidDigit)? as i8;
+        let exponent = match data[7] {
+            b'+' => exponent,
+            b'-' => -exponent,
+            b => return Err(EbdsError::InvalidSign(b)),
+        };
+
+        Ok(Self {
+            index: data[0],
+            currency,
+            base_value,
+            exponent,
+            orientation: data[10].try_into()?,
+            note_type: data[11],
+            series: data[12],
+            compatibility: data[13],
+            version: data[14],
+        })
+    }
+
+    /// Encodes the extended note data block, with zero reserved bytes.
+    ///
+    /// The base value and exponent are clamped to three and two digits.
+    pub fn to_bytes(&self) -> [u8; NOTE_DATA_LEN] {
+        let code = self.currency.alpha().as_bytes();
+        let base = self.base_value.min(999);
+        let exp = self.exponent.unsigned_abs().min(99);
+        let digit = |v: u16| b'0' + (v % 10) as u8;
+
+        [
+            self.index,
+            code[0],
+            code[1],
+            code[2],
+            digit(base / 100),
+            digit(base / 10),
+            digit(base),
+            if self.exponent < 0 { b'-' } else { b'+' },
+            digit(exp as u16 / 10),
+            digit(exp as u16),
+            self.orientation as u8,
+            self.note_type,
+            self.series,
+            self.compatibility,
+            self.version,
+            0,
+            0,
+            0,
+        ]
+    }
+
+    /// Gets the face value of the note, in minor units of its currency.
+    pub fn face_value(&self) -> Result<Money, EbdsError> {
+        let exp = self.exponent as i32 + self.currency.exponent() as i32;
+        let base = self.base_value as i128;
+
+        let amount = if exp >= 0 {
+            10i128
+                .checked_pow(exp as u32)
+                .and_then(|pow| base.checked_mul(pow))
+        } else {
+            10i128
+                .checked_pow(exp.unsigned_abs())
+                .filter(|pow| base % pow == 0)
+                .map(|pow| base / pow)
+        };
+
+        amount
+            .map(|amount| Money::from_minor(self.currency, amount))
+            .ok_or(EbdsError::InvalidValue)
+    }
+}
+
+impl TryFrom<&[u8]> for NoteDescriptor {
+    type Error = EbdsError;
+
+    fn try_from(val: &[u8]) -> Result<Self, Self::Error> {
+        Self::parse(val)
+    }
+}
+
+impl From<NoteDescriptor> for [u8; NOTE_DATA_LEN] {
+    fn from(val: NoteDescriptor) -> Self {
+        val.to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let data = b"\x01USD001+00\x02ABCD\x00\x00\x00";
+        let note = NoteDescriptor::parse(data).unwrap();
+        assert_eq!(
+            note,
+            NoteDescriptor {
+                index: 1,
+                currency: Currency::USD,
+                base_value: 1,
+                exponent: 0,
+                orientation: Orientation::LeftUp,
+                note_type: b'A',
+                series: b'B',
+                compatibility: b'C',
+                version: b'D',
+            }
+        );
+        assert_eq!(&note.to_bytes(), data);
+        assert_eq!(note.face_value(), Ok(Money::from_minor(Currency::USD, 100)));
+
+        assert_eq!(
+            NoteDescriptor::parse(&data[..17]),
+            Err(EbdsError::InvalidLength)
+        );
+        assert_eq!(
+            NoteDescriptor::parse(b"\x01QQQ001+00\x02ABCD\x00\x00\x00"),
+            Err(EbdsError::UnknownCurrency(*b"QQQ"))
+        );
+        assert_eq!(
+            NoteDescriptor::parse(b"\x01USD0A1+00\x02ABCD\x00\x00\x00"),
+            Err(EbdsError::InvalidDigit)
+        );
+        assert_eq!(
+            NoteDescriptor::parse(b"\x01USD001*00\x02ABCD\x00\x00\x00"),
+            Err(EbdsError::InvalidSign(b'*'))
+        );
+        assert_eq!(
+            NoteDescriptor::parse(b"\x01USD001+00\x07ABCD\x00\x00\x00"),
+            Err(EbdsError::InvalidOrientation(0x07))
+        );
+    }
+
+    #[test]
+    fn test_face_value() {
+        let note = |data: &[u8]| NoteDescriptor::parse(data).unwrap();
+
+        assert_eq!(
+            note(b"\x0AJPY100+01\xFFAAAA\x00\x00\x00").face_value(),
+            Ok(Money::from_minor(Currency::JPY, 1000))
+        );
+        assert_eq!(
+            note(b"\x0AEUR002+02\xFFAAAA\x00\x00\x00").face_value(),
+            Ok(Money::from_minor(Currency::EUR, 20000))
+        );
+        // 500 * 10^-2 = 5.00
+        assert_eq!(
+            note(b"\x0AGBP500-02\xFFAAAA\x00\x00\x00").face_value(),
+            Ok(Money::from_minor(Currency::GBP, 500))
+        );
+        assert_eq!(
+            note(b"\x0AJPY005-01\xFFAAAA\x00\x00\x00").face_value(),
+            Err(EbdsError::InvalidValue)
+        );
+        assert_eq!(
+            note(b"\x0AUSD999+99\xFFAAAA\x00\x00\x00").face_value(),
+            Err(EbdsError::InvalidValue)
+        );
+
+        let note = note(b"\x0AKWD020-01\x03AAAA\x00\x00\x00");
+        assert_eq!(note.exponent, -1);
+        assert_eq!(note.orientation, Orientation::LeftDown);
+        assert_eq!(
+            note.face_value(),
+            Ok(Money::from_minor(Currency::KWD, 2000))
+        );
+        assert_eq!(&note.to_bytes(), b"\x0AKWD020-01\x03AAAA\x00\x00\x00");
+    }
+}
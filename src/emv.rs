@@ -0,0 +1,196 @@
+//! EMV encoding of currency codes and exponents.
+//!
+//! EMV data objects carry the numeric currency code as `n3`, packed into two bytes of BCD with a
+//! leading zero nibble (e.g. `08 40` for USD), and the currency exponent as `n1` in one byte.
+//!
+//! ```
+//! use currency_iso4217::{emv, Currency};
+//!
+//! assert_eq!(Currency::USD.to_bcd(), [0x08, 0x40]);
+//! assert_eq!(Currency::from_bcd(&[0x09, 0x78]), Ok(Currency::EUR));
+//! assert_eq!(
+//!     emv::encode_exponent(emv::TAG_TRANSACTION_CURRENCY_EXPONENT, Currency::JPY),
+//!     [0x5F, 0x36, 0x01, 0x00],
+//! );
+//! ```
+
+use crate::std::fmt;
+use crate::Currency;
+
+/// Tag `5F2A`: Transaction Currency Code.
+pub const TAG_TRANSACTION_CURRENCY_CODE: [u8; 2] = [0x5F, 0x2A];
+/// Tag `5F36`: Transaction Currency Exponent.
+pub const TAG_TRANSACTION_CURRENCY_EXPONENT: [u8; 2] = [0x5F, 0x36];
+/// Tag `9F42`: Application Currency Code.
+pub const TAG_APPLICATION_CURRENCY_CODE: [u8; 2] = [0x9F, 0x42];
+/// Tag `9F44`: Application Currency Exponent.
+pub const TAG_APPLICATION_CURRENCY_EXPONENT: [u8; 2] = [0x9F, 0x44];
+/// Tag `9F51`: Application Currency Code (payment system proprietary data).
+pub const TAG_PROPRIETARY_CURRENCY_CODE: [u8; 2] = [0x9F, 0x51];
+
+/// Errors returned when decoding EMV currency data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EmvError {
+    /// The value or data object has the wrong length.
+    InvalidLength,
+    /// A nibble is not a decimal digit.
+    InvalidBcd,
+    /// The data object has an unexpected tag.
+    UnexpectedTag([u8; 2]),
+    /// The numeric code is not a known currency.
+    UnknownCurrency(u16),
+}
+
+impl fmt::Display for EmvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "invalid length"),
+            Self::InvalidBcd => write!(f, "invalid BCD digit"),
+            Self::UnexpectedTag([a, b]) => write!(f, "unexpected tag {a:02X}{b:02X}"),
+            Self::UnknownCurrency(code) => write!(f, "unknown numeric currency code {code:03}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmvError {}
+
+impl Currency {
+    /// Gets the numeric code as `n3` packed BCD, e.g. `[0x08, 0x40]` for USD.
+    pub const fn to_bcd(&self) -> [u8; 2] {
+        let code = *self as u32;
+        [
+            (code / 100) as u8,
+            (((code / 10 % 10) << 4) | (code % 10)) as u8,
+        ]
+    }
+
+    /// Decodes a numeric code from `n3` packed BCD, e.g. `[0x08, 0x40]` for USD.
+    pub fn from_bcd(bcd: &[u8]) -> Result<Self, EmvError> {
+        if bcd.len() != 2 {
+            return Err(EmvError::InvalidLength);
+        }
+        // the leading pad nibble must be zero
+        let code = unpack_bcd(bcd)?;
+        if code > 999 {
+            return Err(EmvError::InvalidBcd);
+        }
+
+        Self::from_numeric(code).ok_or(EmvError::UnknownCurrency(code as u16))
+    }
+
+    /// Gets the exponent as `n1` BCD, e.g. `0x02` for USD.
+    pub const fn to_bcd_exponent(&self) -> u8 {
+        self.exponent()
+    }
+}
+
+/// Encodes a currency code data object, e.g. `5F 2A 02 08 40` for USD.
+pub const fn encode_currency(tag: [u8; 2], currency: Currency) -> [u8; 5] {
+    let [hi, lo] = currency.to_bcd();
+    [tag[0], tag[1], 0x02, hi, lo]
+}
+
+/// Decodes a currency code data object, checking its tag.
+pub fn decode_currency(tag: [u8; 2], tlv: &[u8]) -> Result<Currency, EmvError> {
+    Currency::from_bcd(value(tag, tlv)?)
+}
+
+/// Encodes a currency exponent data object, e.g. `5F 36 01 02` for USD.
+pub const fn encode_exponent(tag: [u8; 2], currency: Currency) -> [u8; 4] {
+    [tag[0], tag[1], 0x01, currency.to_bcd_exponent()]
+}
+
+/// Decodes a currency exponent data object, checking its tag.
+pub fn decode_exponent(tag: [u8; 2], tlv: &[u8]) -> Result<u8, EmvError> {
+    match value(tag, tlv)? {
+        [exp] if *exp <= 9 => Ok(*exp),
+        [_] => Err(EmvError::InvalidBcd),
+        _ => Err(EmvError::InvalidLength),
+    }
+}
+
+/// Gets the value of a primitive data object with a two-byte tag and a short length.
+fn value(tag: [u8; 2], tlv: &[u8]) -> Result<&[u8], EmvError> {
+    match tlv {
+        [a, b, ..] if [*a, *b] != tag => Err(EmvError::UnexpectedTag([*a, *b])),
+        [_, _, len, value @ ..] if *len as usize == value.len() => Ok(value),
+        _ => Err(EmvError::InvalidLength),
+    }
+}
+
+/// Unpacks BCD digits into an integer, two digits per byte.
+pub(crate) fn unpack_bcd(bcd: &[u8]) -> Result<u32, EmvError> {
+    if bcd.len() > 4 {
+        return Err(EmvError::InvalidLength);
+    }
+    bcd.iter().try_fold(0u32, |acc, &b| {
+        let (hi, lo) = ((b >> 4) as u32, (b & 0x0F) as u32);
+        if hi > 9 || lo > 9 {
+            Err(EmvError::InvalidBcd)
+        } else {
+            Ok(acc * 100 + hi * 10 + lo)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bcd() {
+        assert_eq!(Currency::USD.to_bcd(), [0x08, 0x40]);
+        assert_eq!(Currency::ALL.to_bcd(), [0x00, 0x08]);
+        assert_eq!(Currency::XXX.to_bcd(), [0x09, 0x99]);
+
+        for cur in Currency::LIST {
+            assert_eq!(Currency::from_bcd(&cur.to_bcd()), Ok(cur));
+        }
+
+        assert_eq!(Currency::from_bcd(&[0x08]), Err(EmvError::InvalidLength));
+        assert_eq!(
+            Currency::from_bcd(&[0x00, 0x08, 0x40]),
+            Err(EmvError::InvalidLength)
+        );
+        assert_eq!(Currency::from_bcd(&[0x10, 0x00]), Err(EmvError::InvalidBcd));
+        assert_eq!(Currency::from_bcd(&[0x08, 0x4A]), Err(EmvError::InvalidBcd));
+        assert_eq!(
+            Currency::from_bcd(&[0x00, 0x01]),
+            Err(EmvError::UnknownCurrency(1))
+        );
+    }
+
+    #[test]
+    fn test_tlv() {
+        let code = encode_currency(TAG_TRANSACTION_CURRENCY_CODE, Currency::USD);
+        assert_eq!(code, [0x5F, 0x2A, 0x02, 0x08, 0x40]);
+        assert_eq!(
+            decode_currency(TAG_TRANSACTION_CURRENCY_CODE, &code),
+            Ok(Currency::USD)
+        );
+        assert_eq!(
+            decode_currency(TAG_PROPRIETARY_CURRENCY_CODE, &code),
+            Err(EmvError::UnexpectedTag([0x5F, 0x2A]))
+        );
+        assert_eq!(
+            decode_currency(TAG_TRANSACTION_CURRENCY_CODE, &code[..4]),
+            Err(EmvError::InvalidLength)
+        );
+
+        let exp = encode_exponent(TAG_TRANSACTION_CURRENCY_EXPONENT, Currency::KWD);
+        assert_eq!(exp, [0x5F, 0x36, 0x01, 0x03]);
+        assert_eq!(
+            decode_exponent(TAG_TRANSACTION_CURRENCY_EXPONENT, &exp),
+            Ok(3)
+        );
+        assert_eq!(
+            decode_exponent(TAG_TRANSACTION_CURRENCY_EXPONENT, &[0x5F, 0x36, 0x01, 0x0A]),
+            Err(EmvError::InvalidBcd)
+        );
+        assert_eq!(
+            encode_exponent(TAG_APPLICATION_CURRENCY_EXPONENT, Currency::XAU),
+            [0x9F, 0x44, 0x01, 0x00]
+        );
+    }
+}
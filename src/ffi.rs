@@ -0,0 +1,188 @@
+//! C API, declared in `include/iso4217.h`.
+//!
+//! Currencies are passed as their numeric code, with `0` for unknown currencies. Strings
+//! returned to C are static and NUL-terminated, and must not be freed.
+//!
+//! ```
+//! use currency_iso4217::ffi::*;
+//!
+//! let usd = unsafe { iso4217_from_alpha(c"usd".as_ptr()) };
+//! assert_eq!(usd, 840);
+//! assert_eq!(iso4217_minor_units(usd), 2);
+//! assert_eq!(iso4217_minor_units(iso4217_from_numeric(959)), ISO4217_MINOR_UNITS_NA);
+//! assert_eq!(iso4217_from_numeric(1), 0);
+//! ```
+
+use crate::std::ffi::{c_char, c_int, CStr};
+use crate::std::ptr;
+use crate::Currency;
+
+/// Returned by [iso4217_minor_units] for currencies where minor units are not applicable.
+pub const ISO4217_MINOR_UNITS_NA: c_int = -1;
+
+/// Returned by [iso4217_minor_units] for unknown currencies.
+pub const ISO4217_UNKNOWN: c_int = -2;
+
+const COUNT: usize = Currency::LIST.len();
+
+static ALPHAS: [[u8; Currency::LEN + 1]; COUNT] = {
+    let mut out = [[0; Currency::LEN + 1]; COUNT];
+    let mut i = 0;
+    while i < COUNT {
+        let alpha = Currency::LIST[i].alpha().as_bytes();
+        out[i] = [alpha[0], alpha[1], alpha[2], 0];
+        i += 1;
+    }
+    out
+};
+
+const NAMES_LEN: usize = {
+    let mut len = 0;
+    let mut i = 0;
+    while i < COUNT {
+        len += Currency::LIST[i].name().len() + 1;
+        i += 1;
+    }
+    len
+};
+
+// NUL-terminated names, concatenated in the order of `Currency::LIST`.
+static NAMES: [u8; NAMES_LEN] = {
+    let mut out = [0; NAMES_LEN];
+    let mut pos = 0;
+    let mut i = 0;
+    while i < COUNT {
+        let name = Currency::LIST[i].name().as_bytes();
+        let mut j = 0;
+        while j < name.len() {
+            out[pos] = name[j];
+            pos += 1;
+            j += 1;
+        }
+        pos += 1;
+        i += 1;
+    }
+    out
+};
+
+static NAME_OFFSETS: [usize; COUNT] = {
+    let mut out = [0; COUNT];
+    let mut pos = 0;
+    let mut i = 0;
+    while i < COUNT {
+        out[i] = pos;
+        pos += Currency::LIST[i].name().len() + 1;
+        i += 1;
+    }
+    out
+};
+
+fn lookup(currency: u16) -> Option<(Currency, usize)> {
+    let cur = Currency::from_numeric(currency.into())?;
+    let index = Currency::LIST.iter().position(|&c| c == cur)?;
+    Some((cur, index))
+}
+
+/// Looks up a currency by its three-letter alphabetic code, ignoring ASCII case.
+///
+/// Returns the numeric code, or `0` if `alpha` is null or not a known code.
+///
+/// # Safety
+///
+/// `alpha` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iso4217_from_alpha(alpha: *const c_char) -> u16 {
+    if alpha.is_null() {
+        return 0;
+    }
+
+    CStr::from_ptr(alpha)
+        .to_str()
+        .ok()
+        .and_then(|s| s.parse::<Currency>().ok())
+        .map_or(0, |cur| cur as u16)
+}
+
+/// Looks up a currency by its numeric code.
+///
+/// Returns `numeric` if it is a known code, otherwise `0`.
+#[no_mangle]
+pub extern "C" fn iso4217_from_numeric(numeric: u16) -> u16 {
+    lookup(numeric).map_or(0, |(cur, _)| cur as u16)
+}
+
+/// Gets the alphabetic code of a currency, e.g. `"USD"`, or null for unknown currencies.
+#[no_mangle]
+pub extern "C" fn iso4217_alpha(currency: u16) -> *const c_char {
+    lookup(currency).map_or(ptr::null(), |(_, i)| ALPHAS[i].as_ptr().cast())
+}
+
+/// Gets the name of a currency, e.g. `"US Dollar"`, or null for unknown currencies.
+#[no_mangle]
+pub extern "C" fn iso4217_name(currency: u16) -> *const c_char {
+    lookup(currency).map_or(ptr::null(), |(_, i)| {
+        NAMES[NAME_OFFSETS[i]..].as_ptr().cast()
+    })
+}
+
+/// Gets the number of minor units of a currency.
+///
+/// Returns [ISO4217_MINOR_UNITS_NA] where minor units are not applicable, and
+/// [ISO4217_UNKNOWN] for unknown currencies.
+#[no_mangle]
+pub extern "C" fn iso4217_minor_units(currency: u16) -> c_int {
+    match lookup(currency) {
+        Some((cur, _)) => cur
+            .minor_units()
+            .map_or(ISO4217_MINOR_UNITS_NA, c_int::from),
+        None => ISO4217_UNKNOWN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_str(ptr: *const c_char) -> &'static str {
+        assert!(!ptr.is_null());
+        unsafe { CStr::from_ptr(ptr) }.to_str().unwrap()
+    }
+
+    #[test]
+    fn test_strings() {
+        for cur in Currency::LIST {
+            let code = cur as u16;
+            assert_eq!(iso4217_from_numeric(code), code);
+            assert_eq!(to_str(iso4217_alpha(code)), cur.alpha());
+            assert_eq!(to_str(iso4217_name(code)), cur.name());
+
+            let alpha = iso4217_alpha(code);
+            assert_eq!(unsafe { iso4217_from_alpha(alpha) }, code);
+        }
+
+        assert!(iso4217_alpha(0).is_null());
+        assert!(iso4217_name(1000).is_null());
+    }
+
+    #[test]
+    fn test_from_alpha() {
+        let from_alpha = |s: &CStr| unsafe { iso4217_from_alpha(s.as_ptr()) };
+
+        assert_eq!(from_alpha(c"EUR"), 978);
+        assert_eq!(from_alpha(c"jPy"), 392);
+        assert_eq!(from_alpha(c"QQQ"), 0);
+        assert_eq!(from_alpha(c"EURO"), 0);
+        assert_eq!(from_alpha(c""), 0);
+        assert_eq!(unsafe { iso4217_from_alpha(ptr::null()) }, 0);
+    }
+
+    #[test]
+    fn test_minor_units() {
+        assert_eq!(iso4217_minor_units(840), 2);
+        assert_eq!(iso4217_minor_units(392), 0);
+        assert_eq!(iso4217_minor_units(414), 3);
+        assert_eq!(iso4217_minor_units(959), ISO4217_MINOR_UNITS_NA);
+        assert_eq!(iso4217_minor_units(0), ISO4217_UNKNOWN);
+        assert_eq!(iso4217_from_numeric(0), 0);
+    }
+}
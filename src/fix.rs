@@ -0,0 +1,343 @@
+//! FIX tag=value currency fields: `Currency(15)`, `SettlCurrAmt(119)` and `SettlCurrency(120)`.
+//!
+//! FIX currency values are case-sensitive ISO 4217 codes, so `usd` is rejected unless parsing
+//! with [Case::Insensitive]. Invalid values map to the `SessionRejectReason(373)` a session
+//! should reject the message with, see [FixError::reject_reason].
+//!
+//! ```
+//! use currency_iso4217::fix::{self, Case, CurrencyFields};
+//! use currency_iso4217::{Currency, Money};
+//!
+//! let msg = b"8=FIX.4.4\x0135=8\x0115=EUR\x01119=1085.50\x01120=USD\x0110=000\x01";
+//! let fields = CurrencyFields::parse(msg, Case::Exact).unwrap();
+//! assert_eq!(fields.currency, Some(Currency::EUR));
+//! assert_eq!(
+//!     fields.settl_money(),
+//!     Ok(Some(Money::from_minor(Currency::USD, 108550)))
+//! );
+//!
+//! assert!(fix::parse_currency(fix::TAG_CURRENCY, b"eur", Case::Exact).is_err());
+//! ```
+
+use crate::std::fmt;
+use crate::{Currency, Decimal, Money};
+
+/// Field delimiter, `SOH`.
+pub const SOH: u8 = 0x01;
+/// Tag `15`: `Currency`.
+pub const TAG_CURRENCY: u32 = 15;
+/// Tag `119`: `SettlCurrAmt`, in `SettlCurrency`.
+pub const TAG_SETTL_CURR_AMT: u32 = 119;
+/// Tag `120`: `SettlCurrency`.
+pub const TAG_SETTL_CURRENCY: u32 = 120;
+
+/// Case handling of currency values.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Case {
+    /// Only upper-case codes, as required by FIX.
+    #[default]
+    Exact,
+    /// Also lower-case and mixed-case codes, for counterparties that send them.
+    Insensitive,
+}
+
+/// Errors returned when parsing FIX currency fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FixError {
+    /// The field is not `tag=value` with a numeric tag.
+    InvalidField,
+    /// The tag has an empty value.
+    MissingValue(u32),
+    /// A required tag is missing, e.g. `SettlCurrency(120)` for `SettlCurrAmt(119)`.
+    MissingTag(u32),
+    /// The value is not three letters, or not a number.
+    IncorrectFormat(u32),
+    /// The value is not upper-case, with [Case::Exact].
+    NotUppercase(u32),
+    /// The value is not a known currency.
+    UnknownCurrency(u32, [u8; 3]),
+    /// The amount has more decimal digits than the minor units of the currency.
+    Precision(u32, Currency),
+}
+
+impl FixError {
+    /// Gets the tag of the field in error, if known.
+    pub const fn tag(&self) -> Option<u32> {
+        match self {
+            Self::InvalidField => None,
+            Self::MissingValue(tag)
+            | Self::MissingTag(tag)
+            | Self::IncorrectFormat(tag)
+            | Self::NotUppercase(tag)
+            | Self::UnknownCurrency(tag, _)
+            | Self::Precision(tag, _) => Some(*tag),
+        }
+    }
+
+    /// Gets the `SessionRejectReason(373)` for the error.
+    pub const fn reject_reason(&self) -> u32 {
+        match self {
+            // Invalid tag number
+            Self::InvalidField => 0,
+            // Required tag missing
+            Self::MissingTag(_) => 1,
+            // Tag specified without a value
+            Self::MissingValue(_) => 4,
+            // Value is incorrect (out of range) for this tag
+            Self::UnknownCurrency(..) | Self::Precision(..) => 5,
+            // Incorrect data format for value
+            Self::IncorrectFormat(_) | Self::NotUppercase(_) => 6,
+        }
+    }
+}
+
+impl fmt::Display for FixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidField => write!(f, "invalid field"),
+            Self::MissingValue(tag) => write!(f, "tag {tag} specified without a value"),
+            Self::MissingTag(tag) => write!(f, "required tag {tag} missing"),
+            Self::IncorrectFormat(tag) => write!(f, "incorrect data format for tag {tag}"),
+            Self::NotUppercase(tag) => write!(f, "currency in tag {tag} must be upper-case"),
+            Self::UnknownCurrency(tag, code) => write!(
+                f,
+                "unknown currency {} in tag {tag}",
+                crate::std::str::from_utf8(code).unwrap_or_default()
+            ),
+            Self::Precision(tag, c) => write!(
+                f,
+                "amount in tag {tag} exceeds {} decimal digits allowed for {}",
+                c.exponent(),
+                c.alpha()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FixError {}
+
+/// Parses a currency value, e.g. `USD`, of the given tag.
+pub fn parse_currency(tag: u32, value: &[u8], case: Case) -> Result<Currency, FixError> {
+    let code: [u8; 3] = match value {
+        [] => return Err(FixError::MissingValue(tag)),
+        [a, b, c] if value.iter().all(u8::is_ascii_alphabetic) => [*a, *b, *c],
+        _ => return Err(FixError::IncorrectFormat(tag)),
+    };
+
+    let upper = code.map(|b| b.to_ascii_uppercase());
+    if upper != code && case == Case::Exact {
+        return Err(FixError::NotUppercase(tag));
+    }
+    Currency::from_alpha(&upper).ok_or(FixError::UnknownCurrency(tag, code))
+}
+
+/// Parses an amount value, e.g. `1085.50`, of the given tag.
+pub fn parse_amount(tag: u32, value: &[u8]) -> Result<Decimal, FixError> {
+    if value.is_empty() {
+        return Err(FixError::MissingValue(tag));
+    }
+    crate::std::str::from_utf8(value)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or(FixError::IncorrectFormat(tag))
+}
+
+/// Splits a `tag=value` field, without its `SOH` delimiter.
+pub fn split_field(field: &[u8]) -> Result<(u32, &[u8]), FixError> {
+    let eq = field
+        .iter()
+        .position(|&b| b == b'=')
+        .ok_or(FixError::InvalidField)?;
+    let (tag, value) = (&field[..eq], &field[eq + 1..]);
+
+    if tag.is_empty() || tag.len() > 9 || tag[0] == b'0' || !tag.iter().all(u8::is_ascii_digit) {
+        return Err(FixError::InvalidField);
+    }
+    let tag = tag
+        .iter()
+        .fold(0u32, |acc, &d| acc * 10 + (d - b'0') as u32);
+    Ok((tag, value))
+}
+
+/// Writes a currency field, e.g. `15=USD` followed by `SOH`.
+pub fn write_currency(f: &mut dyn fmt::Write, tag: u32, currency: Currency) -> fmt::Result {
+    write!(f, "{tag}={}\x01", currency.alpha())
+}
+
+/// Writes an amount field with the minor units of its currency, e.g. `119=1085.50` followed by
+/// `SOH`.
+pub fn write_amount(f: &mut dyn fmt::Write, tag: u32, money: Money) -> fmt::Result {
+    write!(f, "{tag}={}\x01", money.to_decimal())
+}
+
+/// The currency fields of a FIX message.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CurrencyFields {
+    /// `Currency(15)`.
+    pub currency: Option<Currency>,
+    /// `SettlCurrency(120)`.
+    pub settl_currency: Option<Currency>,
+    /// `SettlCurrAmt(119)`, in `SettlCurrency`.
+    pub settl_curr_amt: Option<Decimal>,
+}
+
+impl CurrencyFields {
+    /// Parses the currency fields of a message, ignoring other fields.
+    ///
+    /// Only the first occurrence of each tag is used, as repeating groups are not interpreted.
+    pub fn parse(message: &[u8], case: Case) -> Result<Self, FixError> {
+        let mut fields = Self::default();
+
+        for field in message.split(|&b| b == SOH).filter(|f| !f.is_empty()) {
+            let (tag, value) = split_field(field)?;
+            match tag {
+                TAG_CURRENCY if fields.currency.is_none() => {
+                    fields.currency = Some(parse_currency(tag, value, case)?);
+                }
+                TAG_SETTL_CURRENCY if fields.settl_currency.is_none() => {
+                    fields.settl_currency = Some(parse_currency(tag, value, case)?);
+                }
+                TAG_SETTL_CURR_AMT if fields.settl_curr_amt.is_none() => {
+                    fields.settl_curr_amt = Some(parse_amount(tag, value)?);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(fields)
+    }
+
+    /// Gets `SettlCurrAmt(119)` as [Money] in `SettlCurrency(120)`, `None` if there is no
+    /// settlement amount.
+    pub fn settl_money(&self) -> Result<Option<Money>, FixError> {
+        let Some(amount) = self.settl_curr_amt else {
+            return Ok(None);
+        };
+        let currency = self
+            .settl_currency
+            .ok_or(FixError::MissingTag(TAG_SETTL_CURRENCY))?;
+
+        Money::try_from_decimal(currency, amount)
+            .map(Some)
+            .map_err(|_| FixError::Precision(TAG_SETTL_CURR_AMT, currency))
+    }
+
+    /// Writes the fields that are set, in tag order.
+    pub fn write(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        if let Some(currency) = self.currency {
+            write_currency(f, TAG_CURRENCY, currency)?;
+        }
+        if let Some(amount) = self.settl_curr_amt {
+            write!(f, "{TAG_SETTL_CURR_AMT}={amount}\x01")?;
+        }
+        if let Some(currency) = self.settl_currency {
+            write_currency(f, TAG_SETTL_CURRENCY, currency)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn test_parse_currency() {
+        assert_eq!(
+            parse_currency(TAG_CURRENCY, b"USD", Case::Exact),
+            Ok(Currency::USD)
+        );
+        assert_eq!(
+            parse_currency(TAG_CURRENCY, b"usd", Case::Exact),
+            Err(FixError::NotUppercase(TAG_CURRENCY))
+        );
+        assert_eq!(
+            parse_currency(TAG_CURRENCY, b"usd", Case::Insensitive),
+            Ok(Currency::USD)
+        );
+        assert_eq!(
+            parse_currency(TAG_SETTL_CURRENCY, b"Eur", Case::Insensitive),
+            Ok(Currency::EUR)
+        );
+        assert_eq!(
+            parse_currency(TAG_CURRENCY, b"", Case::Exact),
+            Err(FixError::MissingValue(TAG_CURRENCY))
+        );
+        assert_eq!(
+            parse_currency(TAG_CURRENCY, b"US", Case::Exact),
+            Err(FixError::IncorrectFormat(TAG_CURRENCY))
+        );
+        assert_eq!(
+            parse_currency(TAG_CURRENCY, b"840", Case::Exact),
+            Err(FixError::IncorrectFormat(TAG_CURRENCY))
+        );
+
+        let err = parse_currency(TAG_CURRENCY, b"QQQ", Case::Exact).unwrap_err();
+        assert_eq!(err, FixError::UnknownCurrency(TAG_CURRENCY, *b"QQQ"));
+        assert_eq!(err.reject_reason(), 5);
+        assert_eq!(err.tag(), Some(TAG_CURRENCY));
+        assert_eq!(FixError::NotUppercase(15).reject_reason(), 6);
+    }
+
+    #[test]
+    fn test_currency_fields() {
+        let msg = b"8=FIX.4.4\x0135=8\x0115=GBP\x01120=JPY\x01119=150000\x01";
+        let fields = CurrencyFields::parse(msg, Case::Exact).unwrap();
+        assert_eq!(fields.currency, Some(Currency::GBP));
+        assert_eq!(
+            fields.settl_money(),
+            Ok(Some(Money::from_minor(Currency::JPY, 150000)))
+        );
+
+        let mut out = String::new();
+        fields.write(&mut out).unwrap();
+        assert_eq!(out, "15=GBP\x01119=150000\x01120=JPY\x01");
+
+        let fields = CurrencyFields::parse(b"119=10.5\x01120=JPY\x01", Case::Exact).unwrap();
+        assert_eq!(
+            fields.settl_money(),
+            Err(FixError::Precision(TAG_SETTL_CURR_AMT, Currency::JPY))
+        );
+        let fields = CurrencyFields::parse(b"119=10.5\x01", Case::Exact).unwrap();
+        assert_eq!(
+            fields.settl_money(),
+            Err(FixError::MissingTag(TAG_SETTL_CURRENCY))
+        );
+        assert_eq!(
+            CurrencyFields::parse(b"", Case::Exact)
+                .unwrap()
+                .settl_money(),
+            Ok(None)
+        );
+
+        assert_eq!(
+            CurrencyFields::parse(b"15=usd\x01", Case::Exact),
+            Err(FixError::NotUppercase(TAG_CURRENCY))
+        );
+        assert_eq!(
+            CurrencyFields::parse(b"119=abc\x01", Case::Exact),
+            Err(FixError::IncorrectFormat(TAG_SETTL_CURR_AMT))
+        );
+        assert_eq!(
+            CurrencyFields::parse(b"15USD\x01", Case::Exact),
+            Err(FixError::InvalidField)
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let mut out = String::new();
+        write_currency(&mut out, TAG_CURRENCY, Currency::CHF).unwrap();
+        write_amount(
+            &mut out,
+            TAG_SETTL_CURR_AMT,
+            Money::from_minor(Currency::KWD, 1500),
+        )
+        .unwrap();
+        assert_eq!(out, "15=CHF\x01119=1.500\x01");
+        assert_eq!(split_field(b"120=USD"), Ok((120, &b"USD"[..])));
+        assert_eq!(split_field(b"=USD"), Err(FixError::InvalidField));
+    }
+}
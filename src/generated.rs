@@ -0,0 +1,1976 @@
+// @generated by `cargo run -p currency-iso4217-codegen` from data/list-one.xml and data/list-three.xml.
+// Do not edit by hand, update the data files instead.
+
+use crate::{Amendment, Date, HistoricCurrency};
+
+/// Publication date of the ISO 4217 list the tables were generated from.
+pub const PUBLISHED_DATE: Date = match Date::new(2023, 1, 1) { Some(date) => date, None => panic!("invalid date") };
+
+/// Latest ISO 4217 amendment reflected in the tables.
+pub const DATA_AMENDMENT: u16 = 174;
+
+/// Version of the ISO 4217 data: publication date and amendment number.
+pub const DATA_VERSION: &str = "2023-01-01 (amendment 174)";
+
+/// ISO 4217 currency codes <https://en.wikipedia.org/wiki/ISO_4217>
+#[repr(u32)]
+#[allow(clippy::zero_prefixed_literal)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Currency {
+    /// UAE Dirham
+    AED = 784,
+    /// Afghani
+    AFN = 971,
+    /// Lek
+    ALL = 008,
+    /// Armenian Dram
+    AMD = 051,
+    /// Netherlands Antillean Guilder
+    ANG = 532,
+    /// Kwanza
+    AOA = 973,
+    /// Argentine Peso
+    ARS = 032,
+    /// Australian Dollar
+    AUD = 036,
+    /// Aruban Florin
+    AWG = 533,
+    /// Azerbaijan Manat
+    AZN = 944,
+    /// Convertible Mark
+    BAM = 977,
+    /// Barbados Dollar
+    BBD = 052,
+    /// Taka
+    BDT = 050,
+    /// Bulgarian Lev
+    BGN = 975,
+    /// Bahraini Dinar
+    BHD = 048,
+    /// Burundi Franc
+    BIF = 108,
+    /// Bermudian Dollar
+    BMD = 060,
+    /// Brunei Dollar
+    BND = 096,
+    /// Boliviano
+    BOB = 068,
+    /// Mvdol
+    BOV = 984,
+    /// Brazilian Real
+    BRL = 986,
+    /// Bahamian Dollar
+    BSD = 044,
+    /// Ngultrum
+    BTN = 064,
+    /// Pula
+    BWP = 072,
+    /// Belarusian Ruble
+    BYN = 933,
+    /// Belize Dollar
+    BZD = 084,
+    /// Canadian Dollar
+    CAD = 124,
+    /// Congolese Franc
+    CDF = 976,
+    /// WIR Euro
+    CHE = 947,
+    /// Swiss Franc
+    CHF = 756,
+    /// WIR Franc
+    CHW = 948,
+    /// Unidad de Fomento
+    CLF = 990,
+    /// Chilean Peso
+    CLP = 152,
+    /// Yuan Renminbi
+    CNY = 156,
+    /// Colombian Peso
+    COP = 170,
+    /// Unidad de Valor Real
+    COU = 970,
+    /// Costa Rican Colon
+    CRC = 188,
+    /// Peso Convertible
+    CUC = 931,
+    /// Cuban Peso
+    CUP = 192,
+    /// Cabo Verde Escudo
+    CVE = 132,
+    /// Czech Koruna
+    CZK = 203,
+    /// Djibouti Franc
+    DJF = 262,
+    /// Danish Krone
+    DKK = 208,
+    /// Dominican Peso
+    DOP = 214,
+    /// Algerian Dinar
+    DZD = 012,
+    /// Egyptian Pound
+    EGP = 818,
+    /// Nakfa
+    ERN = 232,
+    /// Ethiopian Birr
+    ETB = 230,
+    /// Euro
+    EUR = 978,
+    /// Fiji Dollar
+    FJD = 242,
+    /// Falkland Islands Pound
+    FKP = 238,
+    /// Pound Sterling
+    GBP = 826,
+    /// Lari
+    GEL = 981,
+    /// Ghana Cedi
+    GHS = 936,
+    /// Gibraltar Pound
+    GIP = 292,
+    /// Dalasi
+    GMD = 270,
+    /// Guinean Franc
+    GNF = 324,
+    /// Quetzal
+    GTQ = 320,
+    /// Guyana Dollar
+    GYD = 328,
+    /// Hong Kong Dollar
+    HKD = 344,
+    /// Lempira
+    HNL = 340,
+    /// Gourde
+    HTG = 332,
+    /// Forint
+    HUF = 348,
+    /// Rupiah
+    IDR = 360,
+    /// New Israeli Sheqel
+    ILS = 376,
+    /// Indian Rupee
+    INR = 356,
+    /// Iraqi Dinar
+    IQD = 368,
+    /// Iranian Rial
+    IRR = 364,
+    /// Iceland Krona
+    ISK = 352,
+    /// Jamaican Dollar
+    JMD = 388,
+    /// Jordanian Dinar
+    JOD = 400,
+    /// Yen
+    JPY = 392,
+    /// Kenyan Shilling
+    KES = 404,
+    /// Som
+    KGS = 417,
+    /// Riel
+    KHR = 116,
+    /// Comorian Franc
+    KMF = 174,
+    /// North Korean Won
+    KPW = 408,
+    /// Won
+    KRW = 410,
+    /// Kuwaiti Dinar
+    KWD = 414,
+    /// Cayman Islands Dollar
+    KYD = 136,
+    /// Tenge
+    KZT = 398,
+    /// Lao Kip
+    LAK = 418,
+    /// Lebanese Pound
+    LBP = 422,
+    /// Sri Lanka Rupee
+    LKR = 144,
+    /// Liberian Dollar
+    LRD = 430,
+    /// Loti
+    LSL = 426,
+    /// Libyan Dinar
+    LYD = 434,
+    /// Moroccan Dirham
+    MAD = 504,
+    /// Moldovan Leu
+    MDL = 498,
+    /// Malagasy Ariary
+    MGA = 969,
+    /// Denar
+    MKD = 807,
+    /// Kyat
+    MMK = 104,
+    /// Tugrik
+    MNT = 496,
+    /// Pataca
+    MOP = 446,
+    /// Ouguiya
+    MRU = 929,
+    /// Mauritius Rupee
+    MUR = 480,
+    /// Rufiyaa
+    MVR = 462,
+    /// Malawi Kwacha
+    MWK = 454,
+    /// Mexican Peso
+    MXN = 484,
+    /// Mexican Unidad de Inversion (UDI)
+    MXV = 979,
+    /// Malaysian Ringgit
+    MYR = 458,
+    /// Mozambique Metical
+    MZN = 943,
+    /// Namibia Dollar
+    NAD = 516,
+    /// Naira
+    NGN = 566,
+    /// Cordoba Oro
+    NIO = 558,
+    /// Norwegian Krone
+    NOK = 578,
+    /// Nepalese Rupee
+    NPR = 524,
+    /// New Zealand Dollar
+    NZD = 554,
+    /// Rial Omani
+    OMR = 512,
+    /// Balboa
+    PAB = 590,
+    /// Sol
+    PEN = 604,
+    /// Kina
+    PGK = 598,
+    /// Philippine Peso
+    PHP = 608,
+    /// Pakistan Rupee
+    PKR = 586,
+    /// Zloty
+    PLN = 985,
+    /// Guarani
+    PYG = 600,
+    /// Qatari Rial
+    QAR = 634,
+    /// Romanian Leu
+    RON = 946,
+    /// Serbian Dinar
+    RSD = 941,
+    /// Russian Ruble
+    RUB = 643,
+    /// Rwanda Franc
+    RWF = 646,
+    /// Saudi Riyal
+    SAR = 682,
+    /// Solomon Islands Dollar
+    SBD = 090,
+    /// Seychelles Rupee
+    SCR = 690,
+    /// Sudanese Pound
+    SDG = 938,
+    /// Swedish Krona
+    SEK = 752,
+    /// Singapore Dollar
+    SGD = 702,
+    /// Saint Helena Pound
+    SHP = 654,
+    /// Leone
+    SLE = 925,
+    /// Leone
+    SLL = 694,
+    /// Somali Shilling
+    SOS = 706,
+    /// Surinam Dollar
+    SRD = 968,
+    /// South Sudanese Pound
+    SSP = 728,
+    /// Dobra
+    STN = 930,
+    /// El Salvador Colon
+    SVC = 222,
+    /// Syrian Pound
+    SYP = 760,
+    /// Lilangeni
+    SZL = 748,
+    /// Baht
+    THB = 764,
+    /// Somoni
+    TJS = 972,
+    /// Turkmenistan New Manat
+    TMT = 934,
+    /// Tunisian Dinar
+    TND = 788,
+    /// Pa’anga
+    TOP = 776,
+    /// Turkish Lira
+    TRY = 949,
+    /// Trinidad and Tobago Dollar
+    TTD = 780,
+    /// New Taiwan Dollar
+    TWD = 901,
+    /// Tanzanian Shilling
+    TZS = 834,
+    /// Hryvnia
+    UAH = 980,
+    /// Uganda Shilling
+    UGX = 800,
+    /// US Dollar
+    USD = 840,
+    /// US Dollar (Next day)
+    USN = 997,
+    /// Uruguay Peso en Unidades Indexadas (UI)
+    UYI = 940,
+    /// Peso Uruguayo
+    UYU = 858,
+    /// Unidad Previsional
+    UYW = 927,
+    /// Uzbekistan Sum
+    UZS = 860,
+    /// Bolívar Soberano
+    VED = 926,
+    /// Bolívar Soberano
+    VES = 928,
+    /// Dong
+    VND = 704,
+    /// Vatu
+    VUV = 548,
+    /// Tala
+    WST = 882,
+    /// CFA Franc BEAC
+    XAF = 950,
+    /// Silver
+    XAG = 961,
+    /// Gold
+    XAU = 959,
+    /// Bond Markets Unit European Composite Unit (EURCO)
+    XBA = 955,
+    /// Bond Markets Unit European Monetary Unit (E.M.U.-6)
+    XBB = 956,
+    /// Bond Markets Unit European Unit of Account 9 (E.U.A.-9)
+    XBC = 957,
+    /// Bond Markets Unit European Unit of Account 17 (E.U.A.-17)
+    XBD = 958,
+    /// East Caribbean Dollar
+    XCD = 951,
+    /// SDR (Special Drawing Right)
+    XDR = 960,
+    /// CFA Franc BCEAO
+    XOF = 952,
+    /// Palladium
+    XPD = 964,
+    /// CFP Franc
+    XPF = 953,
+    /// Platinum
+    XPT = 962,
+    /// Sucre
+    XSU = 994,
+    /// Codes specifically reserved for testing purposes
+    XTS = 963,
+    /// ADB Unit of Account
+    XUA = 965,
+    /// The codes assigned for transactions where no currency is involved
+    #[default]
+    XXX = 999,
+    /// Yemeni Rial
+    YER = 886,
+    /// Rand
+    ZAR = 710,
+    /// Zambian Kwacha
+    ZMW = 967,
+    /// Zimbabwe Dollar
+    ZWL = 932,
+}
+
+impl Currency {
+    /// All currencies in ISO 4217 list one, sorted by alphabetic code.
+    ///
+    /// Named `LIST` since `ALL` is the Albanian lek.
+    pub const LIST: [Self; 180] = [
+        Self::AED,
+        Self::AFN,
+        Self::ALL,
+        Self::AMD,
+        Self::ANG,
+        Self::AOA,
+        Self::ARS,
+        Self::AUD,
+        Self::AWG,
+        Self::AZN,
+        Self::BAM,
+        Self::BBD,
+        Self::BDT,
+        Self::BGN,
+        Self::BHD,
+        Self::BIF,
+        Self::BMD,
+        Self::BND,
+        Self::BOB,
+        Self::BOV,
+        Self::BRL,
+        Self::BSD,
+        Self::BTN,
+        Self::BWP,
+        Self::BYN,
+        Self::BZD,
+        Self::CAD,
+        Self::CDF,
+        Self::CHE,
+        Self::CHF,
+        Self::CHW,
+        Self::CLF,
+        Self::CLP,
+        Self::CNY,
+        Self::COP,
+        Self::COU,
+        Self::CRC,
+        Self::CUC,
+        Self::CUP,
+        Self::CVE,
+        Self::CZK,
+        Self::DJF,
+        Self::DKK,
+        Self::DOP,
+        Self::DZD,
+        Self::EGP,
+        Self::ERN,
+        Self::ETB,
+        Self::EUR,
+        Self::FJD,
+        Self::FKP,
+        Self::GBP,
+        Self::GEL,
+        Self::GHS,
+        Self::GIP,
+        Self::GMD,
+        Self::GNF,
+        Self::GTQ,
+        Self::GYD,
+        Self::HKD,
+        Self::HNL,
+        Self::HTG,
+        Self::HUF,
+        Self::IDR,
+        Self::ILS,
+        Self::INR,
+        Self::IQD,
+        Self::IRR,
+        Self::ISK,
+        Self::JMD,
+        Self::JOD,
+        Self::JPY,
+        Self::KES,
+        Self::KGS,
+        Self::KHR,
+        Self::KMF,
+        Self::KPW,
+        Self::KRW,
+        Self::KWD,
+        Self::KYD,
+        Self::KZT,
+        Self::LAK,
+        Self::LBP,
+        Self::LKR,
+        Self::LRD,
+        Self::LSL,
+        Self::LYD,
+        Self::MAD,
+        Self::MDL,
+        Self::MGA,
+        Self::MKD,
+        Self::MMK,
+        Self::MNT,
+        Self::MOP,
+        Self::MRU,
+        Self::MUR,
+        Self::MVR,
+        Self::MWK,
+        Self::MXN,
+        Self::MXV,
+        Self::MYR,
+        Self::MZN,
+        Self::NAD,
+        Self::NGN,
+        Self::NIO,
+        Self::NOK,
+        Self::NPR,
+        Self::NZD,
+        Self::OMR,
+        Self::PAB,
+        Self::PEN,
+        Self::PGK,
+        Self::PHP,
+        Self::PKR,
+        Self::PLN,
+        Self::PYG,
+        Self::QAR,
+        Self::RON,
+        Self::RSD,
+        Self::RUB,
+        Self::RWF,
+        Self::SAR,
+        Self::SBD,
+        Self::SCR,
+        Self::SDG,
+        Self::SEK,
+        Self::SGD,
+        Self::SHP,
+        Self::SLE,
+        Self::SLL,
+        Self::SOS,
+        Self::SRD,
+        Self::SSP,
+        Self::STN,
+        Self::SVC,
+        Self::SYP,
+        Self::SZL,
+        Self::THB,
+        Self::TJS,
+        Self::TMT,
+        Self::TND,
+        Self::TOP,
+        Self::TRY,
+        Self::TTD,
+        Self::TWD,
+        Self::TZS,
+        Self::UAH,
+        Self::UGX,
+        Self::USD,
+        Self::USN,
+        Self::UYI,
+        Self::UYU,
+        Self::UYW,
+        Self::UZS,
+        Self::VED,
+        Self::VES,
+        Self::VND,
+        Self::VUV,
+        Self::WST,
+        Self::XAF,
+        Self::XAG,
+        Self::XAU,
+        Self::XBA,
+        Self::XBB,
+        Self::XBC,
+        Self::XBD,
+        Self::XCD,
+        Self::XDR,
+        Self::XOF,
+        Self::XPD,
+        Self::XPF,
+        Self::XPT,
+        Self::XSU,
+        Self::XTS,
+        Self::XUA,
+        Self::XXX,
+        Self::YER,
+        Self::ZAR,
+        Self::ZMW,
+        Self::ZWL,
+    ];
+
+    /// Gets the name of the currency (in English).
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::AED => "UAE Dirham",
+            Self::AFN => "Afghani",
+            Self::ALL => "Lek",
+            Self::AMD => "Armenian Dram",
+            Self::ANG => "Netherlands Antillean Guilder",
+            Self::AOA => "Kwanza",
+            Self::ARS => "Argentine Peso",
+            Self::AUD => "Australian Dollar",
+            Self::AWG => "Aruban Florin",
+            Self::AZN => "Azerbaijan Manat",
+            Self::BAM => "Convertible Mark",
+            Self::BBD => "Barbados Dollar",
+            Self::BDT => "Taka",
+            Self::BGN => "Bulgarian Lev",
+            Self::BHD => "Bahraini Dinar",
+            Self::BIF => "Burundi Franc",
+            Self::BMD => "Bermudian Dollar",
+            Self::BND => "Brunei Dollar",
+            Self::BOB => "Boliviano",
+            Self::BOV => "Mvdol",
+            Self::BRL => "Brazilian Real",
+            Self::BSD => "Bahamian Dollar",
+            Self::BTN => "Ngultrum",
+            Self::BWP => "Pula",
+            Self::BYN => "Belarusian Ruble",
+            Self::BZD => "Belize Dollar",
+            Self::CAD => "Canadian Dollar",
+            Self::CDF => "Congolese Franc",
+            Self::CHE => "WIR Euro",
+            Self::CHF => "Swiss Franc",
+            Self::CHW => "WIR Franc",
+            Self::CLF => "Unidad de Fomento",
+            Self::CLP => "Chilean Peso",
+            Self::CNY => "Yuan Renminbi",
+            Self::COP => "Colombian Peso",
+            Self::COU => "Unidad de Valor Real",
+            Self::CRC => "Costa Rican Colon",
+            Self::CUC => "Peso Convertible",
+            Self::CUP => "Cuban Peso",
+            Self::CVE => "Cabo Verde Escudo",
+            Self::CZK => "Czech Koruna",
+            Self::DJF => "Djibouti Franc",
+            Self::DKK => "Danish Krone",
+            Self::DOP => "Dominican Peso",
+            Self::DZD => "Algerian Dinar",
+            Self::EGP => "Egyptian Pound",
+            Self::ERN => "Nakfa",
+            Self::ETB => "Ethiopian Birr",
+            Self::EUR => "Euro",
+            Self::FJD => "Fiji Dollar",
+            Self::FKP => "Falkland Islands Pound",
+            Self::GBP => "Pound Sterling",
+            Self::GEL => "Lari",
+            Self::GHS => "Ghana Cedi",
+            Self::GIP => "Gibraltar Pound",
+            Self::GMD => "Dalasi",
+            Self::GNF => "Guinean Franc",
+            Self::GTQ => "Quetzal",
+            Self::GYD => "Guyana Dollar",
+            Self::HKD => "Hong Kong Dollar",
+            Self::HNL => "Lempira",
+            Self::HTG => "Gourde",
+            Self::HUF => "Forint",
+            Self::IDR => "Rupiah",
+            Self::ILS => "New Israeli Sheqel",
+            Self::INR => "Indian Rupee",
+            Self::IQD => "Iraqi Dinar",
+            Self::IRR => "Iranian Rial",
+            Self::ISK => "Iceland Krona",
+            Self::JMD => "Jamaican Dollar",
+            Self::JOD => "Jordanian Dinar",
+            Self::JPY => "Yen",
+            Self::KES => "Kenyan Shilling",
+            Self::KGS => "Som",
+            Self::KHR => "Riel",
+            Self::KMF => "Comorian Franc",
+            Self::KPW => "North Korean Won",
+            Self::KRW => "Won",
+            Self::KWD => "Kuwaiti Dinar",
+            Self::KYD => "Cayman Islands Dollar",
+            Self::KZT => "Tenge",
+            Self::LAK => "Lao Kip",
+            Self::LBP => "Lebanese Pound",
+            Self::LKR => "Sri Lanka Rupee",
+            Self::LRD => "Liberian Dollar",
+            Self::LSL => "Loti",
+            Self::LYD => "Libyan Dinar",
+            Self::MAD => "Moroccan Dirham",
+            Self::MDL => "Moldovan Leu",
+            Self::MGA => "Malagasy Ariary",
+            Self::MKD => "Denar",
+            Self::MMK => "Kyat",
+            Self::MNT => "Tugrik",
+            Self::MOP => "Pataca",
+            Self::MRU => "Ouguiya",
+            Self::MUR => "Mauritius Rupee",
+            Self::MVR => "Rufiyaa",
+            Self::MWK => "Malawi Kwacha",
+            Self::MXN => "Mexican Peso",
+            Self::MXV => "Mexican Unidad de Inversion (UDI)",
+            Self::MYR => "Malaysian Ringgit",
+            Self::MZN => "Mozambique Metical",
+            Self::NAD => "Namibia Dollar",
+            Self::NGN => "Naira",
+            Self::NIO => "Cordoba Oro",
+            Self::NOK => "Norwegian Krone",
+            Self::NPR => "Nepalese Rupee",
+            Self::NZD => "New Zealand Dollar",
+            Self::OMR => "Rial Omani",
+            Self::PAB => "Balboa",
+            Self::PEN => "Sol",
+            Self::PGK => "Kina",
+            Self::PHP => "Philippine Peso",
+            Self::PKR => "Pakistan Rupee",
+            Self::PLN => "Zloty",
+            Self::PYG => "Guarani",
+            Self::QAR => "Qatari Rial",
+            Self::RON => "Romanian Leu",
+            Self::RSD => "Serbian Dinar",
+            Self::RUB => "Russian Ruble",
+            Self::RWF => "Rwanda Franc",
+            Self::SAR => "Saudi Riyal",
+            Self::SBD => "Solomon Islands Dollar",
+            Self::SCR => "Seychelles Rupee",
+            Self::SDG => "Sudanese Pound",
+            Self::SEK => "Swedish Krona",
+            Self::SGD => "Singapore Dollar",
+            Self::SHP => "Saint Helena Pound",
+            Self::SLE => "Leone",
+            Self::SLL => "Leone",
+            Self::SOS => "Somali Shilling",
+            Self::SRD => "Surinam Dollar",
+            Self::SSP => "South Sudanese Pound",
+            Self::STN => "Dobra",
+            Self::SVC => "El Salvador Colon",
+            Self::SYP => "Syrian Pound",
+            Self::SZL => "Lilangeni",
+            Self::THB => "Baht",
+            Self::TJS => "Somoni",
+            Self::TMT => "Turkmenistan New Manat",
+            Self::TND => "Tunisian Dinar",
+            Self::TOP => "Pa’anga",
+            Self::TRY => "Turkish Lira",
+            Self::TTD => "Trinidad and Tobago Dollar",
+            Self::TWD => "New Taiwan Dollar",
+            Self::TZS => "Tanzanian Shilling",
+            Self::UAH => "Hryvnia",
+            Self::UGX => "Uganda Shilling",
+            Self::USD => "US Dollar",
+            Self::USN => "US Dollar (Next day)",
+            Self::UYI => "Uruguay Peso en Unidades Indexadas (UI)",
+            Self::UYU => "Peso Uruguayo",
+            Self::UYW => "Unidad Previsional",
+            Self::UZS => "Uzbekistan Sum",
+            Self::VED => "Bolívar Soberano",
+            Self::VES => "Bolívar Soberano",
+            Self::VND => "Dong",
+            Self::VUV => "Vatu",
+            Self::WST => "Tala",
+            Self::XAF => "CFA Franc BEAC",
+            Self::XAG => "Silver",
+            Self::XAU => "Gold",
+            Self::XBA => "Bond Markets Unit European Composite Unit (EURCO)",
+            Self::XBB => "Bond Markets Unit European Monetary Unit (E.M.U.-6)",
+            Self::XBC => "Bond Markets Unit European Unit of Account 9 (E.U.A.-9)",
+            Self::XBD => "Bond Markets Unit European Unit of Account 17 (E.U.A.-17)",
+            Self::XCD => "East Caribbean Dollar",
+            Self::XDR => "SDR (Special Drawing Right)",
+            Self::XOF => "CFA Franc BCEAO",
+            Self::XPD => "Palladium",
+            Self::XPF => "CFP Franc",
+            Self::XPT => "Platinum",
+            Self::XSU => "Sucre",
+            Self::XTS => "Codes specifically reserved for testing purposes",
+            Self::XUA => "ADB Unit of Account",
+            Self::XXX => "The codes assigned for transactions where no currency is involved",
+            Self::YER => "Yemeni Rial",
+            Self::ZAR => "Rand",
+            Self::ZMW => "Zambian Kwacha",
+            Self::ZWL => "Zimbabwe Dollar",
+        }
+    }
+
+    /// Gets the alphabetic code of the currency.
+    pub const fn alpha(&self) -> &'static str {
+        match self {
+            Self::AED => "AED",
+            Self::AFN => "AFN",
+            Self::ALL => "ALL",
+            Self::AMD => "AMD",
+            Self::ANG => "ANG",
+            Self::AOA => "AOA",
+            Self::ARS => "ARS",
+            Self::AUD => "AUD",
+            Self::AWG => "AWG",
+            Self::AZN => "AZN",
+            Self::BAM => "BAM",
+            Self::BBD => "BBD",
+            Self::BDT => "BDT",
+            Self::BGN => "BGN",
+            Self::BHD => "BHD",
+            Self::BIF => "BIF",
+            Self::BMD => "BMD",
+            Self::BND => "BND",
+            Self::BOB => "BOB",
+            Self::BOV => "BOV",
+            Self::BRL => "BRL",
+            Self::BSD => "BSD",
+            Self::BTN => "BTN",
+            Self::BWP => "BWP",
+            Self::BYN => "BYN",
+            Self::BZD => "BZD",
+            Self::CAD => "CAD",
+            Self::CDF => "CDF",
+            Self::CHE => "CHE",
+            Self::CHF => "CHF",
+            Self::CHW => "CHW",
+            Self::CLF => "CLF",
+            Self::CLP => "CLP",
+            Self::CNY => "CNY",
+            Self::COP => "COP",
+            Self::COU => "COU",
+            Self::CRC => "CRC",
+            Self::CUC => "CUC",
+            Self::CUP => "CUP",
+            Self::CVE => "CVE",
+            Self::CZK => "CZK",
+            Self::DJF => "DJF",
+            Self::DKK => "DKK",
+            Self::DOP => "DOP",
+            Self::DZD => "DZD",
+            Self::EGP => "EGP",
+            Self::ERN => "ERN",
+            Self::ETB => "ETB",
+            Self::EUR => "EUR",
+            Self::FJD => "FJD",
+            Self::FKP => "FKP",
+            Self::GBP => "GBP",
+            Self::GEL => "GEL",
+            Self::GHS => "GHS",
+            Self::GIP => "GIP",
+            Self::GMD => "GMD",
+            Self::GNF => "GNF",
+            Self::GTQ => "GTQ",
+            Self::GYD => "GYD",
+            Self::HKD => "HKD",
+            Self::HNL => "HNL",
+            Self::HTG => "HTG",
+            Self::HUF => "HUF",
+            Self::IDR => "IDR",
+            Self::ILS => "ILS",
+            Self::INR => "INR",
+            Self::IQD => "IQD",
+            Self::IRR => "IRR",
+            Self::ISK => "ISK",
+            Self::JMD => "JMD",
+            Self::JOD => "JOD",
+            Self::JPY => "JPY",
+            Self::KES => "KES",
+            Self::KGS => "KGS",
+            Self::KHR => "KHR",
+            Self::KMF => "KMF",
+            Self::KPW => "KPW",
+            Self::KRW => "KRW",
+            Self::KWD => "KWD",
+            Self::KYD => "KYD",
+            Self::KZT => "KZT",
+            Self::LAK => "LAK",
+            Self::LBP => "LBP",
+            Self::LKR => "LKR",
+            Self::LRD => "LRD",
+            Self::LSL => "LSL",
+            Self::LYD => "LYD",
+            Self::MAD => "MAD",
+            Self::MDL => "MDL",
+            Self::MGA => "MGA",
+            Self::MKD => "MKD",
+            Self::MMK => "MMK",
+            Self::MNT => "MNT",
+            Self::MOP => "MOP",
+            Self::MRU => "MRU",
+            Self::MUR => "MUR",
+            Self::MVR => "MVR",
+            Self::MWK => "MWK",
+            Self::MXN => "MXN",
+            Self::MXV => "MXV",
+            Self::MYR => "MYR",
+            Self::MZN => "MZN",
+            Self::NAD => "NAD",
+            Self::NGN => "NGN",
+            Self::NIO => "NIO",
+            Self::NOK => "NOK",
+            Self::NPR => "NPR",
+            Self::NZD => "NZD",
+            Self::OMR => "OMR",
+            Self::PAB => "PAB",
+            Self::PEN => "PEN",
+            Self::PGK => "PGK",
+            Self::PHP => "PHP",
+            Self::PKR => "PKR",
+            Self::PLN => "PLN",
+            Self::PYG => "PYG",
+            Self::QAR => "QAR",
+            Self::RON => "RON",
+            Self::RSD => "RSD",
+            Self::RUB => "RUB",
+            Self::RWF => "RWF",
+            Self::SAR => "SAR",
+            Self::SBD => "SBD",
+            Self::SCR => "SCR",
+            Self::SDG => "SDG",
+            Self::SEK => "SEK",
+            Self::SGD => "SGD",
+            Self::SHP => "SHP",
+            Self::SLE => "SLE",
+            Self::SLL => "SLL",
+            Self::SOS => "SOS",
+            Self::SRD => "SRD",
+            Self::SSP => "SSP",
+            Self::STN => "STN",
+            Self::SVC => "SVC",
+            Self::SYP => "SYP",
+            Self::SZL => "SZL",
+            Self::THB => "THB",
+            Self::TJS => "TJS",
+            Self::TMT => "TMT",
+            Self::TND => "TND",
+            Self::TOP => "TOP",
+            Self::TRY => "TRY",
+            Self::TTD => "TTD",
+            Self::TWD => "TWD",
+            Self::TZS => "TZS",
+            Self::UAH => "UAH",
+            Self::UGX => "UGX",
+            Self::USD => "USD",
+            Self::USN => "USN",
+            Self::UYI => "UYI",
+            Self::UYU => "UYU",
+            Self::UYW => "UYW",
+            Self::UZS => "UZS",
+            Self::VED => "VED",
+            Self::VES => "VES",
+            Self::VND => "VND",
+            Self::VUV => "VUV",
+            Self::WST => "WST",
+            Self::XAF => "XAF",
+            Self::XAG => "XAG",
+            Self::XAU => "XAU",
+            Self::XBA => "XBA",
+            Self::XBB => "XBB",
+            Self::XBC => "XBC",
+            Self::XBD => "XBD",
+            Self::XCD => "XCD",
+            Self::XDR => "XDR",
+            Self::XOF => "XOF",
+            Self::XPD => "XPD",
+            Self::XPF => "XPF",
+            Self::XPT => "XPT",
+            Self::XSU => "XSU",
+            Self::XTS => "XTS",
+            Self::XUA => "XUA",
+            Self::XXX => "XXX",
+            Self::YER => "YER",
+            Self::ZAR => "ZAR",
+            Self::ZMW => "ZMW",
+            Self::ZWL => "ZWL",
+        }
+    }
+
+    /// Gets the number of minor units (digits after the decimal separator).
+    ///
+    /// Returns `None` for codes where minor units are not applicable (precious metals, bond
+    /// market units, testing and "no currency" codes).
+    pub const fn minor_units(&self) -> Option<u8> {
+        match self {
+            Self::AED => Some(2),
+            Self::AFN => Some(2),
+            Self::ALL => Some(2),
+            Self::AMD => Some(2),
+            Self::ANG => Some(2),
+            Self::AOA => Some(2),
+            Self::ARS => Some(2),
+            Self::AUD => Some(2),
+            Self::AWG => Some(2),
+            Self::AZN => Some(2),
+            Self::BAM => Some(2),
+            Self::BBD => Some(2),
+            Self::BDT => Some(2),
+            Self::BGN => Some(2),
+            Self::BHD => Some(3),
+            Self::BIF => Some(0),
+            Self::BMD => Some(2),
+            Self::BND => Some(2),
+            Self::BOB => Some(2),
+            Self::BOV => Some(2),
+            Self::BRL => Some(2),
+            Self::BSD => Some(2),
+            Self::BTN => Some(2),
+            Self::BWP => Some(2),
+            Self::BYN => Some(2),
+            Self::BZD => Some(2),
+            Self::CAD => Some(2),
+            Self::CDF => Some(2),
+            Self::CHE => Some(2),
+            Self::CHF => Some(2),
+            Self::CHW => Some(2),
+            Self::CLF => Some(4),
+            Self::CLP => Some(0),
+            Self::CNY => Some(2),
+            Self::COP => Some(2),
+            Self::COU => Some(2),
+            Self::CRC => Some(2),
+            Self::CUC => Some(2),
+            Self::CUP => Some(2),
+            Self::CVE => Some(2),
+            Self::CZK => Some(2),
+            Self::DJF => Some(0),
+            Self::DKK => Some(2),
+            Self::DOP => Some(2),
+            Self::DZD => Some(2),
+            Self::EGP => Some(2),
+            Self::ERN => Some(2),
+            Self::ETB => Some(2),
+            Self::EUR => Some(2),
+            Self::FJD => Some(2),
+            Self::FKP => Some(2),
+            Self::GBP => Some(2),
+            Self::GEL => Some(2),
+            Self::GHS => Some(2),
+            Self::GIP => Some(2),
+            Self::GMD => Some(2),
+            Self::GNF => Some(0),
+            Self::GTQ => Some(2),
+            Self::GYD => Some(2),
+            Self::HKD => Some(2),
+            Self::HNL => Some(2),
+            Self::HTG => Some(2),
+            Self::HUF => Some(2),
+            Self::IDR => Some(2),
+            Self::ILS => Some(2),
+            Self::INR => Some(2),
+            Self::IQD => Some(3),
+            Self::IRR => Some(2),
+            Self::ISK => Some(0),
+            Self::JMD => Some(2),
+            Self::JOD => Some(3),
+            Self::JPY => Some(0),
+            Self::KES => Some(2),
+            Self::KGS => Some(2),
+            Self::KHR => Some(2),
+            Self::KMF => Some(0),
+            Self::KPW => Some(2),
+            Self::KRW => Some(0),
+            Self::KWD => Some(3),
+            Self::KYD => Some(2),
+            Self::KZT => Some(2),
+            Self::LAK => Some(2),
+            Self::LBP => Some(2),
+            Self::LKR => Some(2),
+            Self::LRD => Some(2),
+            Self::LSL => Some(2),
+            Self::LYD => Some(3),
+            Self::MAD => Some(2),
+            Self::MDL => Some(2),
+            Self::MGA => Some(2),
+            Self::MKD => Some(2),
+            Self::MMK => Some(2),
+            Self::MNT => Some(2),
+            Self::MOP => Some(2),
+            Self::MRU => Some(2),
+            Self::MUR => Some(2),
+            Self::MVR => Some(2),
+            Self::MWK => Some(2),
+            Self::MXN => Some(2),
+            Self::MXV => Some(2),
+            Self::MYR => Some(2),
+            Self::MZN => Some(2),
+            Self::NAD => Some(2),
+            Self::NGN => Some(2),
+            Self::NIO => Some(2),
+            Self::NOK => Some(2),
+            Self::NPR => Some(2),
+            Self::NZD => Some(2),
+            Self::OMR => Some(3),
+            Self::PAB => Some(2),
+            Self::PEN => Some(2),
+            Self::PGK => Some(2),
+            Self::PHP => Some(2),
+            Self::PKR => Some(2),
+            Self::PLN => Some(2),
+            Self::PYG => Some(0),
+            Self::QAR => Some(2),
+            Self::RON => Some(2),
+            Self::RSD => Some(2),
+            Self::RUB => Some(2),
+            Self::RWF => Some(0),
+            Self::SAR => Some(2),
+            Self::SBD => Some(2),
+            Self::SCR => Some(2),
+            Self::SDG => Some(2),
+            Self::SEK => Some(2),
+            Self::SGD => Some(2),
+            Self::SHP => Some(2),
+            Self::SLE => Some(2),
+            Self::SLL => Some(2),
+            Self::SOS => Some(2),
+            Self::SRD => Some(2),
+            Self::SSP => Some(2),
+            Self::STN => Some(2),
+            Self::SVC => Some(2),
+            Self::SYP => Some(2),
+            Self::SZL => Some(2),
+            Self::THB => Some(2),
+            Self::TJS => Some(2),
+            Self::TMT => Some(2),
+            Self::TND => Some(3),
+            Self::TOP => Some(2),
+            Self::TRY => Some(2),
+            Self::TTD => Some(2),
+            Self::TWD => Some(2),
+            Self::TZS => Some(2),
+            Self::UAH => Some(2),
+            Self::UGX => Some(0),
+            Self::USD => Some(2),
+            Self::USN => Some(2),
+            Self::UYI => Some(0),
+            Self::UYU => Some(2),
+            Self::UYW => Some(4),
+            Self::UZS => Some(2),
+            Self::VED => Some(2),
+            Self::VES => Some(2),
+            Self::VND => Some(0),
+            Self::VUV => Some(0),
+            Self::WST => Some(2),
+            Self::XAF => Some(0),
+            Self::XAG => None,
+            Self::XAU => None,
+            Self::XBA => None,
+            Self::XBB => None,
+            Self::XBC => None,
+            Self::XBD => None,
+            Self::XCD => Some(2),
+            Self::XDR => None,
+            Self::XOF => Some(0),
+            Self::XPD => None,
+            Self::XPF => Some(0),
+            Self::XPT => None,
+            Self::XSU => None,
+            Self::XTS => None,
+            Self::XUA => None,
+            Self::XXX => None,
+            Self::YER => Some(2),
+            Self::ZAR => Some(2),
+            Self::ZMW => Some(2),
+            Self::ZWL => Some(2),
+        }
+    }
+
+    /// Gets whether the code is a funds code rather than a currency, e.g. [Currency::USN].
+    pub const fn is_fund(&self) -> bool {
+        matches!(
+            self,
+            Self::BOV
+                | Self::CHE
+                | Self::CHW
+                | Self::CLF
+                | Self::COU
+                | Self::MXV
+                | Self::USN
+                | Self::UYI
+        )
+    }
+
+    /// Gets the names of the countries and other entities using the currency, as listed in
+    /// ISO 4217.
+    pub const fn countries(&self) -> &'static [&'static str] {
+        match self {
+            Self::AED => &["UNITED ARAB EMIRATES (THE)"],
+            Self::AFN => &["AFGHANISTAN"],
+            Self::ALL => &["ALBANIA"],
+            Self::AMD => &["ARMENIA"],
+            Self::ANG => &["CURAÇAO", "SINT MAARTEN (DUTCH PART)"],
+            Self::AOA => &["ANGOLA"],
+            Self::ARS => &["ARGENTINA"],
+            Self::AUD => &["AUSTRALIA", "CHRISTMAS ISLAND", "COCOS (KEELING) ISLANDS (THE)", "HEARD ISLAND AND McDONALD ISLANDS", "KIRIBATI", "NAURU", "NORFOLK ISLAND", "TUVALU"],
+            Self::AWG => &["ARUBA"],
+            Self::AZN => &["AZERBAIJAN"],
+            Self::BAM => &["BOSNIA AND HERZEGOVINA"],
+            Self::BBD => &["BARBADOS"],
+            Self::BDT => &["BANGLADESH"],
+            Self::BGN => &["BULGARIA"],
+            Self::BHD => &["BAHRAIN"],
+            Self::BIF => &["BURUNDI"],
+            Self::BMD => &["BERMUDA"],
+            Self::BND => &["BRUNEI DARUSSALAM"],
+            Self::BOB => &["BOLIVIA (PLURINATIONAL STATE OF)"],
+            Self::BOV => &["BOLIVIA (PLURINATIONAL STATE OF)"],
+            Self::BRL => &["BRAZIL"],
+            Self::BSD => &["BAHAMAS (THE)"],
+            Self::BTN => &["BHUTAN"],
+            Self::BWP => &["BOTSWANA"],
+            Self::BYN => &["BELARUS"],
+            Self::BZD => &["BELIZE"],
+            Self::CAD => &["CANADA"],
+            Self::CDF => &["CONGO (THE DEMOCRATIC REPUBLIC OF THE)"],
+            Self::CHE => &["SWITZERLAND"],
+            Self::CHF => &["LIECHTENSTEIN", "SWITZERLAND"],
+            Self::CHW => &["SWITZERLAND"],
+            Self::CLF => &["CHILE"],
+            Self::CLP => &["CHILE"],
+            Self::CNY => &["CHINA"],
+            Self::COP => &["COLOMBIA"],
+            Self::COU => &["COLOMBIA"],
+            Self::CRC => &["COSTA RICA"],
+            Self::CUC => &["CUBA"],
+            Self::CUP => &["CUBA"],
+            Self::CVE => &["CABO VERDE"],
+            Self::CZK => &["CZECHIA"],
+            Self::DJF => &["DJIBOUTI"],
+            Self::DKK => &["DENMARK", "FAROE ISLANDS (THE)", "GREENLAND"],
+            Self::DOP => &["DOMINICAN REPUBLIC (THE)"],
+            Self::DZD => &["ALGERIA"],
+            Self::EGP => &["EGYPT"],
+            Self::ERN => &["ERITREA"],
+            Self::ETB => &["ETHIOPIA"],
+            Self::EUR => &["ÅLAND ISLANDS", "ANDORRA", "AUSTRIA", "BELGIUM", "CROATIA", "CYPRUS", "ESTONIA", "EUROPEAN UNION", "FINLAND", "FRANCE", "FRENCH GUIANA", "FRENCH SOUTHERN TERRITORIES (THE)", "GERMANY", "GREECE", "GUADELOUPE", "HOLY SEE (THE)", "IRELAND", "ITALY", "LATVIA", "LITHUANIA", "LUXEMBOURG", "MALTA", "MARTINIQUE", "MAYOTTE", "MONACO", "MONTENEGRO", "NETHERLANDS (THE)", "PORTUGAL", "RÉUNION", "SAINT BARTHÉLEMY", "SAINT MARTIN (FRENCH PART)", "SAINT PIERRE AND MIQUELON", "SAN MARINO", "SLOVAKIA", "SLOVENIA", "SPAIN"],
+            Self::FJD => &["FIJI"],
+            Self::FKP => &["FALKLAND ISLANDS (THE) [MALVINAS]"],
+            Self::GBP => &["GUERNSEY", "ISLE OF MAN", "JERSEY", "UNITED KINGDOM OF GREAT BRITAIN AND NORTHERN IRELAND (THE)"],
+            Self::GEL => &["GEORGIA"],
+            Self::GHS => &["GHANA"],
+            Self::GIP => &["GIBRALTAR"],
+            Self::GMD => &["GAMBIA (THE)"],
+            Self::GNF => &["GUINEA"],
+            Self::GTQ => &["GUATEMALA"],
+            Self::GYD => &["GUYANA"],
+            Self::HKD => &["HONG KONG"],
+            Self::HNL => &["HONDURAS"],
+            Self::HTG => &["HAITI"],
+            Self::HUF => &["HUNGARY"],
+            Self::IDR => &["INDONESIA"],
+            Self::ILS => &["ISRAEL"],
+            Self::INR => &["BHUTAN", "INDIA"],
+            Self::IQD => &["IRAQ"],
+            Self::IRR => &["IRAN (ISLAMIC REPUBLIC OF)"],
+            Self::ISK => &["ICELAND"],
+            Self::JMD => &["JAMAICA"],
+            Self::JOD => &["JORDAN"],
+            Self::JPY => &["JAPAN"],
+            Self::KES => &["KENYA"],
+            Self::KGS => &["KYRGYZSTAN"],
+            Self::KHR => &["CAMBODIA"],
+            Self::KMF => &["COMOROS (THE)"],
+            Self::KPW => &["KOREA (THE DEMOCRATIC PEOPLE’S REPUBLIC OF)"],
+            Self::KRW => &["KOREA (THE REPUBLIC OF)"],
+            Self::KWD => &["KUWAIT"],
+            Self::KYD => &["CAYMAN ISLANDS (THE)"],
+            Self::KZT => &["KAZAKHSTAN"],
+            Self::LAK => &["LAO PEOPLE’S DEMOCRATIC REPUBLIC (THE)"],
+            Self::LBP => &["LEBANON"],
+            Self::LKR => &["SRI LANKA"],
+            Self::LRD => &["LIBERIA"],
+            Self::LSL => &["LESOTHO"],
+            Self::LYD => &["LIBYA"],
+            Self::MAD => &["MOROCCO", "WESTERN SAHARA"],
+            Self::MDL => &["MOLDOVA (THE REPUBLIC OF)"],
+            Self::MGA => &["MADAGASCAR"],
+            Self::MKD => &["NORTH MACEDONIA"],
+            Self::MMK => &["MYANMAR"],
+            Self::MNT => &["MONGOLIA"],
+            Self::MOP => &["MACAO"],
+            Self::MRU => &["MAURITANIA"],
+            Self::MUR => &["MAURITIUS"],
+            Self::MVR => &["MALDIVES"],
+            Self::MWK => &["MALAWI"],
+            Self::MXN => &["MEXICO"],
+            Self::MXV => &["MEXICO"],
+            Self::MYR => &["MALAYSIA"],
+            Self::MZN => &["MOZAMBIQUE"],
+            Self::NAD => &["NAMIBIA"],
+            Self::NGN => &["NIGERIA"],
+            Self::NIO => &["NICARAGUA"],
+            Self::NOK => &["BOUVET ISLAND", "NORWAY", "SVALBARD AND JAN MAYEN"],
+            Self::NPR => &["NEPAL"],
+            Self::NZD => &["COOK ISLANDS (THE)", "NEW ZEALAND", "NIUE", "PITCAIRN", "TOKELAU"],
+            Self::OMR => &["OMAN"],
+            Self::PAB => &["PANAMA"],
+            Self::PEN => &["PERU"],
+            Self::PGK => &["PAPUA NEW GUINEA"],
+            Self::PHP => &["PHILIPPINES (THE)"],
+            Self::PKR => &["PAKISTAN"],
+            Self::PLN => &["POLAND"],
+            Self::PYG => &["PARAGUAY"],
+            Self::QAR => &["QATAR"],
+            Self::RON => &["ROMANIA"],
+            Self::RSD => &["SERBIA"],
+            Self::RUB => &["RUSSIAN FEDERATION (THE)"],
+            Self::RWF => &["RWANDA"],
+            Self::SAR => &["SAUDI ARABIA"],
+            Self::SBD => &["SOLOMON ISLANDS"],
+            Self::SCR => &["SEYCHELLES"],
+            Self::SDG => &["SUDAN (THE)"],
+            Self::SEK => &["SWEDEN"],
+            Self::SGD => &["SINGAPORE"],
+            Self::SHP => &["SAINT HELENA, ASCENSION AND TRISTAN DA CUNHA"],
+            Self::SLE => &["SIERRA LEONE"],
+            Self::SLL => &["SIERRA LEONE"],
+            Self::SOS => &["SOMALIA"],
+            Self::SRD => &["SURINAME"],
+            Self::SSP => &["SOUTH SUDAN"],
+            Self::STN => &["SAO TOME AND PRINCIPE"],
+            Self::SVC => &["EL SALVADOR"],
+            Self::SYP => &["SYRIAN ARAB REPUBLIC"],
+            Self::SZL => &["ESWATINI"],
+            Self::THB => &["THAILAND"],
+            Self::TJS => &["TAJIKISTAN"],
+            Self::TMT => &["TURKMENISTAN"],
+            Self::TND => &["TUNISIA"],
+            Self::TOP => &["TONGA"],
+            Self::TRY => &["TÜRKİYE"],
+            Self::TTD => &["TRINIDAD AND TOBAGO"],
+            Self::TWD => &["TAIWAN (PROVINCE OF CHINA)"],
+            Self::TZS => &["TANZANIA, UNITED REPUBLIC OF"],
+            Self::UAH => &["UKRAINE"],
+            Self::UGX => &["UGANDA"],
+            Self::USD => &["AMERICAN SAMOA", "BONAIRE, SINT EUSTATIUS AND SABA", "BRITISH INDIAN OCEAN TERRITORY (THE)", "ECUADOR", "EL SALVADOR", "GUAM", "HAITI", "MARSHALL ISLANDS (THE)", "MICRONESIA (FEDERATED STATES OF)", "NORTHERN MARIANA ISLANDS (THE)", "PALAU", "PANAMA", "PUERTO RICO", "TIMOR-LESTE", "TURKS AND CAICOS ISLANDS (THE)", "UNITED STATES MINOR OUTLYING ISLANDS (THE)", "UNITED STATES OF AMERICA (THE)", "VIRGIN ISLANDS (BRITISH)", "VIRGIN ISLANDS (U.S.)"],
+            Self::USN => &["UNITED STATES OF AMERICA (THE)"],
+            Self::UYI => &["URUGUAY"],
+            Self::UYU => &["URUGUAY"],
+            Self::UYW => &["URUGUAY"],
+            Self::UZS => &["UZBEKISTAN"],
+            Self::VED => &["VENEZUELA (BOLIVARIAN REPUBLIC OF)"],
+            Self::VES => &["VENEZUELA (BOLIVARIAN REPUBLIC OF)"],
+            Self::VND => &["VIET NAM"],
+            Self::VUV => &["VANUATU"],
+            Self::WST => &["SAMOA"],
+            Self::XAF => &["CAMEROON", "CENTRAL AFRICAN REPUBLIC (THE)", "CHAD", "CONGO (THE)", "EQUATORIAL GUINEA", "GABON"],
+            Self::XAG => &["ZZ11_Silver"],
+            Self::XAU => &["ZZ08_Gold"],
+            Self::XBA => &["ZZ01_Bond Markets Unit European_EURCO"],
+            Self::XBB => &["ZZ02_Bond Markets Unit European_EMU-6"],
+            Self::XBC => &["ZZ03_Bond Markets Unit European_EUA-9"],
+            Self::XBD => &["ZZ04_Bond Markets Unit European_EUA-17"],
+            Self::XCD => &["ANGUILLA", "ANTIGUA AND BARBUDA", "DOMINICA", "GRENADA", "MONTSERRAT", "SAINT KITTS AND NEVIS", "SAINT LUCIA", "SAINT VINCENT AND THE GRENADINES"],
+            Self::XDR => &["INTERNATIONAL MONETARY FUND (IMF)"],
+            Self::XOF => &["BENIN", "BURKINA FASO", "CÔTE D'IVOIRE", "GUINEA-BISSAU", "MALI", "NIGER (THE)", "SENEGAL", "TOGO"],
+            Self::XPD => &["ZZ09_Palladium"],
+            Self::XPF => &["FRENCH POLYNESIA", "NEW CALEDONIA", "WALLIS AND FUTUNA"],
+            Self::XPT => &["ZZ10_Platinum"],
+            Self::XSU => &["SISTEMA UNITARIO DE COMPENSACION REGIONAL DE PAGOS \"SUCRE\""],
+            Self::XTS => &["ZZ06_Testing_Code"],
+            Self::XUA => &["MEMBER COUNTRIES OF THE AFRICAN DEVELOPMENT BANK GROUP"],
+            Self::XXX => &["ZZ07_No_Currency"],
+            Self::YER => &["YEMEN"],
+            Self::ZAR => &["LESOTHO", "NAMIBIA", "SOUTH AFRICA"],
+            Self::ZMW => &["ZAMBIA"],
+            Self::ZWL => &["ZIMBABWE"],
+        }
+    }
+
+    /// Gets the currency for an upper-case alphabetic code, e.g. `b"USD"`.
+    pub const fn from_alpha(code: &[u8; 3]) -> Option<Self> {
+        match code {
+            b"AED" => Some(Self::AED),
+            b"AFN" => Some(Self::AFN),
+            b"ALL" => Some(Self::ALL),
+            b"AMD" => Some(Self::AMD),
+            b"ANG" => Some(Self::ANG),
+            b"AOA" => Some(Self::AOA),
+            b"ARS" => Some(Self::ARS),
+            b"AUD" => Some(Self::AUD),
+            b"AWG" => Some(Self::AWG),
+            b"AZN" => Some(Self::AZN),
+            b"BAM" => Some(Self::BAM),
+            b"BBD" => Some(Self::BBD),
+            b"BDT" => Some(Self::BDT),
+            b"BGN" => Some(Self::BGN),
+            b"BHD" => Some(Self::BHD),
+            b"BIF" => Some(Self::BIF),
+            b"BMD" => Some(Self::BMD),
+            b"BND" => Some(Self::BND),
+            b"BOB" => Some(Self::BOB),
+            b"BOV" => Some(Self::BOV),
+            b"BRL" => Some(Self::BRL),
+            b"BSD" => Some(Self::BSD),
+            b"BTN" => Some(Self::BTN),
+            b"BWP" => Some(Self::BWP),
+            b"BYN" => Some(Self::BYN),
+            b"BZD" => Some(Self::BZD),
+            b"CAD" => Some(Self::CAD),
+            b"CDF" => Some(Self::CDF),
+            b"CHE" => Some(Self::CHE),
+            b"CHF" => Some(Self::CHF),
+            b"CHW" => Some(Self::CHW),
+            b"CLF" => Some(Self::CLF),
+            b"CLP" => Some(Self::CLP),
+            b"CNY" => Some(Self::CNY),
+            b"COP" => Some(Self::COP),
+            b"COU" => Some(Self::COU),
+            b"CRC" => Some(Self::CRC),
+            b"CUC" => Some(Self::CUC),
+            b"CUP" => Some(Self::CUP),
+            b"CVE" => Some(Self::CVE),
+            b"CZK" => Some(Self::CZK),
+            b"DJF" => Some(Self::DJF),
+            b"DKK" => Some(Self::DKK),
+            b"DOP" => Some(Self::DOP),
+            b"DZD" => Some(Self::DZD),
+            b"EGP" => Some(Self::EGP),
+            b"ERN" => Some(Self::ERN),
+            b"ETB" => Some(Self::ETB),
+            b"EUR" => Some(Self::EUR),
+            b"FJD" => Some(Self::FJD),
+            b"FKP" => Some(Self::FKP),
+            b"GBP" => Some(Self::GBP),
+            b"GEL" => Some(Self::GEL),
+            b"GHS" => Some(Self::GHS),
+            b"GIP" => Some(Self::GIP),
+            b"GMD" => Some(Self::GMD),
+            b"GNF" => Some(Self::GNF),
+            b"GTQ" => Some(Self::GTQ),
+            b"GYD" => Some(Self::GYD),
+            b"HKD" => Some(Self::HKD),
+            b"HNL" => Some(Self::HNL),
+            b"HTG" => Some(Self::HTG),
+            b"HUF" => Some(Self::HUF),
+            b"IDR" => Some(Self::IDR),
+            b"ILS" => Some(Self::ILS),
+            b"INR" => Some(Self::INR),
+            b"IQD" => Some(Self::IQD),
+            b"IRR" => Some(Self::IRR),
+            b"ISK" => Some(Self::ISK),
+            b"JMD" => Some(Self::JMD),
+            b"JOD" => Some(Self::JOD),
+            b"JPY" => Some(Self::JPY),
+            b"KES" => Some(Self::KES),
+            b"KGS" => Some(Self::KGS),
+            b"KHR" => Some(Self::KHR),
+            b"KMF" => Some(Self::KMF),
+            b"KPW" => Some(Self::KPW),
+            b"KRW" => Some(Self::KRW),
+            b"KWD" => Some(Self::KWD),
+            b"KYD" => Some(Self::KYD),
+            b"KZT" => Some(Self::KZT),
+            b"LAK" => Some(Self::LAK),
+            b"LBP" => Some(Self::LBP),
+            b"LKR" => Some(Self::LKR),
+            b"LRD" => Some(Self::LRD),
+            b"LSL" => Some(Self::LSL),
+            b"LYD" => Some(Self::LYD),
+            b"MAD" => Some(Self::MAD),
+            b"MDL" => Some(Self::MDL),
+            b"MGA" => Some(Self::MGA),
+            b"MKD" => Some(Self::MKD),
+            b"MMK" => Some(Self::MMK),
+            b"MNT" => Some(Self::MNT),
+            b"MOP" => Some(Self::MOP),
+            b"MRU" => Some(Self::MRU),
+            b"MUR" => Some(Self::MUR),
+            b"MVR" => Some(Self::MVR),
+            b"MWK" => Some(Self::MWK),
+            b"MXN" => Some(Self::MXN),
+            b"MXV" => Some(Self::MXV),
+            b"MYR" => Some(Self::MYR),
+            b"MZN" => Some(Self::MZN),
+            b"NAD" => Some(Self::NAD),
+            b"NGN" => Some(Self::NGN),
+            b"NIO" => Some(Self::NIO),
+            b"NOK" => Some(Self::NOK),
+            b"NPR" => Some(Self::NPR),
+            b"NZD" => Some(Self::NZD),
+            b"OMR" => Some(Self::OMR),
+            b"PAB" => Some(Self::PAB),
+            b"PEN" => Some(Self::PEN),
+            b"PGK" => Some(Self::PGK),
+            b"PHP" => Some(Self::PHP),
+            b"PKR" => Some(Self::PKR),
+            b"PLN" => Some(Self::PLN),
+            b"PYG" => Some(Self::PYG),
+            b"QAR" => Some(Self::QAR),
+            b"RON" => Some(Self::RON),
+            b"RSD" => Some(Self::RSD),
+            b"RUB" => Some(Self::RUB),
+            b"RWF" => Some(Self::RWF),
+            b"SAR" => Some(Self::SAR),
+            b"SBD" => Some(Self::SBD),
+            b"SCR" => Some(Self::SCR),
+            b"SDG" => Some(Self::SDG),
+            b"SEK" => Some(Self::SEK),
+            b"SGD" => Some(Self::SGD),
+            b"SHP" => Some(Self::SHP),
+            b"SLE" => Some(Self::SLE),
+            b"SLL" => Some(Self::SLL),
+            b"SOS" => Some(Self::SOS),
+            b"SRD" => Some(Self::SRD),
+            b"SSP" => Some(Self::SSP),
+            b"STN" => Some(Self::STN),
+            b"SVC" => Some(Self::SVC),
+            b"SYP" => Some(Self::SYP),
+            b"SZL" => Some(Self::SZL),
+            b"THB" => Some(Self::THB),
+            b"TJS" => Some(Self::TJS),
+            b"TMT" => Some(Self::TMT),
+            b"TND" => Some(Self::TND),
+            b"TOP" => Some(Self::TOP),
+            b"TRY" => Some(Self::TRY),
+            b"TTD" => Some(Self::TTD),
+            b"TWD" => Some(Self::TWD),
+            b"TZS" => Some(Self::TZS),
+            b"UAH" => Some(Self::UAH),
+            b"UGX" => Some(Self::UGX),
+            b"USD" => Some(Self::USD),
+            b"USN" => Some(Self::USN),
+            b"UYI" => Some(Self::UYI),
+            b"UYU" => Some(Self::UYU),
+            b"UYW" => Some(Self::UYW),
+            b"UZS" => Some(Self::UZS),
+            b"VED" => Some(Self::VED),
+            b"VES" => Some(Self::VES),
+            b"VND" => Some(Self::VND),
+            b"VUV" => Some(Self::VUV),
+            b"WST" => Some(Self::WST),
+            b"XAF" => Some(Self::XAF),
+            b"XAG" => Some(Self::XAG),
+            b"XAU" => Some(Self::XAU),
+            b"XBA" => Some(Self::XBA),
+            b"XBB" => Some(Self::XBB),
+            b"XBC" => Some(Self::XBC),
+            b"XBD" => Some(Self::XBD),
+            b"XCD" => Some(Self::XCD),
+            b"XDR" => Some(Self::XDR),
+            b"XOF" => Some(Self::XOF),
+            b"XPD" => Some(Self::XPD),
+            b"XPF" => Some(Self::XPF),
+            b"XPT" => Some(Self::XPT),
+            b"XSU" => Some(Self::XSU),
+            b"XTS" => Some(Self::XTS),
+            b"XUA" => Some(Self::XUA),
+            b"XXX" => Some(Self::XXX),
+            b"YER" => Some(Self::YER),
+            b"ZAR" => Some(Self::ZAR),
+            b"ZMW" => Some(Self::ZMW),
+            b"ZWL" => Some(Self::ZWL),
+            _ => None,
+        }
+    }
+
+    /// Gets the currency for a numeric code, e.g. `840`.
+    pub const fn from_numeric(code: u32) -> Option<Self> {
+        match code {
+            784 => Some(Self::AED),
+            971 => Some(Self::AFN),
+            8 => Some(Self::ALL),
+            51 => Some(Self::AMD),
+            532 => Some(Self::ANG),
+            973 => Some(Self::AOA),
+            32 => Some(Self::ARS),
+            36 => Some(Self::AUD),
+            533 => Some(Self::AWG),
+            944 => Some(Self::AZN),
+            977 => Some(Self::BAM),
+            52 => Some(Self::BBD),
+            50 => Some(Self::BDT),
+            975 => Some(Self::BGN),
+            48 => Some(Self::BHD),
+            108 => Some(Self::BIF),
+            60 => Some(Self::BMD),
+            96 => Some(Self::BND),
+            68 => Some(Self::BOB),
+            984 => Some(Self::BOV),
+            986 => Some(Self::BRL),
+            44 => Some(Self::BSD),
+            64 => Some(Self::BTN),
+            72 => Some(Self::BWP),
+            933 => Some(Self::BYN),
+            84 => Some(Self::BZD),
+            124 => Some(Self::CAD),
+            976 => Some(Self::CDF),
+            947 => Some(Self::CHE),
+            756 => Some(Self::CHF),
+            948 => Some(Self::CHW),
+            990 => Some(Self::CLF),
+            152 => Some(Self::CLP),
+            156 => Some(Self::CNY),
+            170 => Some(Self::COP),
+            970 => Some(Self::COU),
+            188 => Some(Self::CRC),
+            931 => Some(Self::CUC),
+            192 => Some(Self::CUP),
+            132 => Some(Self::CVE),
+            203 => Some(Self::CZK),
+            262 => Some(Self::DJF),
+            208 => Some(Self::DKK),
+            214 => Some(Self::DOP),
+            12 => Some(Self::DZD),
+            818 => Some(Self::EGP),
+            232 => Some(Self::ERN),
+            230 => Some(Self::ETB),
+            978 => Some(Self::EUR),
+            242 => Some(Self::FJD),
+            238 => Some(Self::FKP),
+            826 => Some(Self::GBP),
+            981 => Some(Self::GEL),
+            936 => Some(Self::GHS),
+            292 => Some(Self::GIP),
+            270 => Some(Self::GMD),
+            324 => Some(Self::GNF),
+            320 => Some(Self::GTQ),
+            328 => Some(Self::GYD),
+            344 => Some(Self::HKD),
+            340 => Some(Self::HNL),
+            332 => Some(Self::HTG),
+            348 => Some(Self::HUF),
+            360 => Some(Self::IDR),
+            376 => Some(Self::ILS),
+            356 => Some(Self::INR),
+            368 => Some(Self::IQD),
+            364 => Some(Self::IRR),
+            352 => Some(Self::ISK),
+            388 => Some(Self::JMD),
+            400 => Some(Self::JOD),
+            392 => Some(Self::JPY),
+            404 => Some(Self::KES),
+            417 => Some(Self::KGS),
+            116 => Some(Self::KHR),
+            174 => Some(Self::KMF),
+            408 => Some(Self::KPW),
+            410 => Some(Self::KRW),
+            414 => Some(Self::KWD),
+            136 => Some(Self::KYD),
+            398 => Some(Self::KZT),
+            418 => Some(Self::LAK),
+            422 => Some(Self::LBP),
+            144 => Some(Self::LKR),
+            430 => Some(Self::LRD),
+            426 => Some(Self::LSL),
+            434 => Some(Self::LYD),
+            504 => Some(Self::MAD),
+            498 => Some(Self::MDL),
+            969 => Some(Self::MGA),
+            807 => Some(Self::MKD),
+            104 => Some(Self::MMK),
+            496 => Some(Self::MNT),
+            446 => Some(Self::MOP),
+            929 => Some(Self::MRU),
+            480 => Some(Self::MUR),
+            462 => Some(Self::MVR),
+            454 => Some(Self::MWK),
+            484 => Some(Self::MXN),
+            979 => Some(Self::MXV),
+            458 => Some(Self::MYR),
+            943 => Some(Self::MZN),
+            516 => Some(Self::NAD),
+            566 => Some(Self::NGN),
+            558 => Some(Self::NIO),
+            578 => Some(Self::NOK),
+            524 => Some(Self::NPR),
+            554 => Some(Self::NZD),
+            512 => Some(Self::OMR),
+            590 => Some(Self::PAB),
+            604 => Some(Self::PEN),
+            598 => Some(Self::PGK),
+            608 => Some(Self::PHP),
+            586 => Some(Self::PKR),
+            985 => Some(Self::PLN),
+            600 => Some(Self::PYG),
+            634 => Some(Self::QAR),
+            946 => Some(Self::RON),
+            941 => Some(Self::RSD),
+            643 => Some(Self::RUB),
+            646 => Some(Self::RWF),
+            682 => Some(Self::SAR),
+            90 => Some(Self::SBD),
+            690 => Some(Self::SCR),
+            938 => Some(Self::SDG),
+            752 => Some(Self::SEK),
+            702 => Some(Self::SGD),
+            654 => Some(Self::SHP),
+            925 => Some(Self::SLE),
+            694 => Some(Self::SLL),
+            706 => Some(Self::SOS),
+            968 => Some(Self::SRD),
+            728 => Some(Self::SSP),
+            930 => Some(Self::STN),
+            222 => Some(Self::SVC),
+            760 => Some(Self::SYP),
+            748 => Some(Self::SZL),
+            764 => Some(Self::THB),
+            972 => Some(Self::TJS),
+            934 => Some(Self::TMT),
+            788 => Some(Self::TND),
+            776 => Some(Self::TOP),
+            949 => Some(Self::TRY),
+            780 => Some(Self::TTD),
+            901 => Some(Self::TWD),
+            834 => Some(Self::TZS),
+            980 => Some(Self::UAH),
+            800 => Some(Self::UGX),
+            840 => Some(Self::USD),
+            997 => Some(Self::USN),
+            940 => Some(Self::UYI),
+            858 => Some(Self::UYU),
+            927 => Some(Self::UYW),
+            860 => Some(Self::UZS),
+            926 => Some(Self::VED),
+            928 => Some(Self::VES),
+            704 => Some(Self::VND),
+            548 => Some(Self::VUV),
+            882 => Some(Self::WST),
+            950 => Some(Self::XAF),
+            961 => Some(Self::XAG),
+            959 => Some(Self::XAU),
+            955 => Some(Self::XBA),
+            956 => Some(Self::XBB),
+            957 => Some(Self::XBC),
+            958 => Some(Self::XBD),
+            951 => Some(Self::XCD),
+            960 => Some(Self::XDR),
+            952 => Some(Self::XOF),
+            964 => Some(Self::XPD),
+            953 => Some(Self::XPF),
+            962 => Some(Self::XPT),
+            994 => Some(Self::XSU),
+            963 => Some(Self::XTS),
+            965 => Some(Self::XUA),
+            999 => Some(Self::XXX),
+            886 => Some(Self::YER),
+            710 => Some(Self::ZAR),
+            967 => Some(Self::ZMW),
+            932 => Some(Self::ZWL),
+            _ => None,
+        }
+    }
+
+    /// Gets the number of the ISO 4217 amendment that added the currency.
+    ///
+    /// Returns `None` for currencies that predate the tracked [AMENDMENTS].
+    pub const fn added_in(&self) -> Option<u16> {
+        match self {
+            Self::BYN => Some(161),
+            Self::MRU => Some(165),
+            Self::SLE => Some(172),
+            Self::STN => Some(165),
+            Self::UYW => Some(169),
+            Self::VED => Some(170),
+            Self::VES => Some(168),
+            _ => None,
+        }
+    }
+}
+
+/// Tracked ISO 4217 amendments, oldest first.
+pub const AMENDMENTS: &[Amendment] = &[
+    Amendment {
+        number: 161,
+        published: match Date::new(2016, 7, 1) { Some(date) => date, None => panic!("invalid date") },
+        added: &["BYN"],
+        withdrawn: &["BYR"],
+    },
+    Amendment {
+        number: 165,
+        published: match Date::new(2017, 11, 27) { Some(date) => date, None => panic!("invalid date") },
+        added: &["MRU", "STN"],
+        withdrawn: &[],
+    },
+    Amendment {
+        number: 168,
+        published: match Date::new(2018, 8, 20) { Some(date) => date, None => panic!("invalid date") },
+        added: &["VES"],
+        withdrawn: &[],
+    },
+    Amendment {
+        number: 169,
+        published: match Date::new(2018, 8, 29) { Some(date) => date, None => panic!("invalid date") },
+        added: &["UYW"],
+        withdrawn: &[],
+    },
+    Amendment {
+        number: 170,
+        published: match Date::new(2021, 10, 1) { Some(date) => date, None => panic!("invalid date") },
+        added: &["VED"],
+        withdrawn: &[],
+    },
+    Amendment {
+        number: 172,
+        published: match Date::new(2022, 7, 1) { Some(date) => date, None => panic!("invalid date") },
+        added: &["SLE"],
+        withdrawn: &[],
+    },
+    Amendment {
+        number: 174,
+        published: match Date::new(2023, 1, 1) { Some(date) => date, None => panic!("invalid date") },
+        added: &[],
+        withdrawn: &["HRK"],
+    },
+];
+
+/// Currencies withdrawn from ISO 4217 (list three), in list order.
+pub const HISTORIC: &[HistoricCurrency] = &[
+    HistoricCurrency {
+        code: "ADP",
+        number: Some(20),
+        name: "Andorran Peseta",
+        country: "ANDORRA",
+        withdrawn: "2003-07",
+    },
+    HistoricCurrency {
+        code: "ATS",
+        number: Some(40),
+        name: "Schilling",
+        country: "AUSTRIA",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "BYR",
+        number: Some(974),
+        name: "Belarusian Ruble",
+        country: "BELARUS",
+        withdrawn: "2017-01",
+    },
+    HistoricCurrency {
+        code: "BEF",
+        number: Some(56),
+        name: "Belgian Franc",
+        country: "BELGIUM",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "HRK",
+        number: Some(191),
+        name: "Kuna",
+        country: "CROATIA",
+        withdrawn: "2023-01",
+    },
+    HistoricCurrency {
+        code: "CYP",
+        number: Some(196),
+        name: "Cyprus Pound",
+        country: "CYPRUS",
+        withdrawn: "2008-01",
+    },
+    HistoricCurrency {
+        code: "EEK",
+        number: Some(233),
+        name: "Kroon",
+        country: "ESTONIA",
+        withdrawn: "2011-01",
+    },
+    HistoricCurrency {
+        code: "XEU",
+        number: Some(954),
+        name: "European Currency Unit (E.C.U)",
+        country: "EUROPEAN MONETARY CO-OPERATION FUND (EMCF)",
+        withdrawn: "1999-01",
+    },
+    HistoricCurrency {
+        code: "FIM",
+        number: Some(246),
+        name: "Markka",
+        country: "FINLAND",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "FRF",
+        number: Some(250),
+        name: "French Franc",
+        country: "FRANCE",
+        withdrawn: "1999-01",
+    },
+    HistoricCurrency {
+        code: "DEM",
+        number: Some(276),
+        name: "Deutsche Mark",
+        country: "GERMANY",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "GRD",
+        number: Some(300),
+        name: "Drachma",
+        country: "GREECE",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "IEP",
+        number: Some(372),
+        name: "Irish Pound",
+        country: "IRELAND",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "ITL",
+        number: Some(380),
+        name: "Italian Lira",
+        country: "ITALY",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "LVL",
+        number: Some(428),
+        name: "Latvian Lats",
+        country: "LATVIA",
+        withdrawn: "2014-01",
+    },
+    HistoricCurrency {
+        code: "LTL",
+        number: Some(440),
+        name: "Lithuanian Litas",
+        country: "LITHUANIA",
+        withdrawn: "2014-12",
+    },
+    HistoricCurrency {
+        code: "LUF",
+        number: Some(442),
+        name: "Luxembourg Franc",
+        country: "LUXEMBOURG",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "MTL",
+        number: Some(470),
+        name: "Maltese Lira",
+        country: "MALTA",
+        withdrawn: "2008-01",
+    },
+    HistoricCurrency {
+        code: "MRO",
+        number: Some(478),
+        name: "Ouguiya",
+        country: "MAURITANIA",
+        withdrawn: "2017-12",
+    },
+    HistoricCurrency {
+        code: "NLG",
+        number: Some(528),
+        name: "Netherlands Guilder",
+        country: "NETHERLANDS (THE)",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "PTE",
+        number: Some(620),
+        name: "Portuguese Escudo",
+        country: "PORTUGAL",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "ROL",
+        number: Some(642),
+        name: "Old Leu",
+        country: "ROMANIA",
+        withdrawn: "2005-06",
+    },
+    HistoricCurrency {
+        code: "STD",
+        number: Some(678),
+        name: "Dobra",
+        country: "SAO TOME AND PRINCIPE",
+        withdrawn: "2017-12",
+    },
+    HistoricCurrency {
+        code: "SKK",
+        number: Some(703),
+        name: "Slovak Koruna",
+        country: "SLOVAKIA",
+        withdrawn: "2009-01",
+    },
+    HistoricCurrency {
+        code: "SIT",
+        number: Some(705),
+        name: "Tolar",
+        country: "SLOVENIA",
+        withdrawn: "2007-01",
+    },
+    HistoricCurrency {
+        code: "ESP",
+        number: Some(724),
+        name: "Spanish Peseta",
+        country: "SPAIN",
+        withdrawn: "2002-03",
+    },
+    HistoricCurrency {
+        code: "TRL",
+        number: Some(792),
+        name: "Old Turkish Lira",
+        country: "TURKEY",
+        withdrawn: "2005-12",
+    },
+    HistoricCurrency {
+        code: "VEF",
+        number: Some(937),
+        name: "Bolívar",
+        country: "VENEZUELA (BOLIVARIAN REPUBLIC OF)",
+        withdrawn: "2018-08",
+    },
+    HistoricCurrency {
+        code: "ZMK",
+        number: Some(894),
+        name: "Zambian Kwacha",
+        country: "ZAMBIA",
+        withdrawn: "2012-12",
+    },
+];
@@ -0,0 +1,423 @@
+//! Cash inventories of cash handling devices, e.g. recyclers and change dispensers.
+//!
+//! ```
+//! use currency_iso4217::dispense::Strategy;
+//! use currency_iso4217::inventory::CashInventory;
+//! use currency_iso4217::{Currency, Money};
+//!
+//! let mut inventory = CashInventory::new();
+//! inventory.deposit(Currency::USD, 2000, 5).unwrap();
+//! inventory.deposit(Currency::USD, 500, 10).unwrap();
+//!
+//! let amount = Money::from_minor(Currency::USD, 3000);
+//! inventory.dispense(amount, Strategy::FewestPieces).unwrap();
+//! assert_eq!(inventory.count(Currency::USD, 2000), 4);
+//! assert_eq!(inventory.total(Currency::USD), Money::from_minor(Currency::USD, 12000));
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::dispense::{self, Dispense, DispenseError, Strategy, MAX_DENOMINATIONS};
+use crate::std::fmt;
+use crate::{Currency, Money};
+
+/// Errors returned by [CashInventory] operations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InventoryError {
+    /// The value is zero, or not a denomination of the currency.
+    InvalidDenomination(Currency, u32),
+    /// There are fewer pieces of the denomination than requested.
+    Insufficient(Currency, u32),
+    /// The count of the denomination would overflow.
+    Overflow(Currency, u32),
+    /// No dispense of the amount is possible.
+    Dispense(DispenseError),
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDenomination(c, value) => {
+                write!(f, "invalid {} denomination {value}", c.alpha())
+            }
+            Self::Insufficient(c, value) => {
+                write!(f, "insufficient {} pieces of {value}", c.alpha())
+            }
+            Self::Overflow(c, value) => write!(f, "{} count of {value} overflows", c.alpha()),
+            Self::Dispense(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InventoryError {}
+
+impl From<DispenseError> for InventoryError {
+    fn from(val: DispenseError) -> Self {
+        Self::Dispense(val)
+    }
+}
+
+/// A difference between the expected and the counted total of a currency.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Discrepancy {
+    /// Expected total.
+    pub expected: Money,
+    /// Total in the inventory.
+    pub actual: Money,
+}
+
+impl Discrepancy {
+    /// Gets the currency.
+    pub const fn currency(&self) -> Currency {
+        self.actual.currency()
+    }
+
+    /// Gets the actual minus the expected total, negative if cash is missing.
+    ///
+    /// Saturates at the bounds of `i128` for extreme expected totals.
+    pub const fn difference(&self) -> Money {
+        Money::from_minor(
+            self.actual.currency(),
+            self.actual.amount().saturating_sub(self.expected.amount()),
+        )
+    }
+}
+
+/// The pieces of one denomination in a [CashInventory].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InventoryEntry {
+    /// Currency of the denomination.
+    pub currency: Currency,
+    /// Value of the denomination in minor units.
+    pub value: u32,
+    /// Number of pieces.
+    pub count: u32,
+}
+
+/// Counts of banknotes and coins per currency and denomination.
+///
+/// With the `serde` feature, a [CashInventory] serializes as a snapshot: a list of
+/// [InventoryEntry], validated again on deserialization.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CashInventory {
+    counts: BTreeMap<(Currency, u32), u32>,
+}
+
+impl CashInventory {
+    /// Creates a new, empty [CashInventory].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the number of pieces of a denomination.
+    pub fn count(&self, currency: Currency, value: u32) -> u32 {
+        self.counts.get(&(currency, value)).copied().unwrap_or(0)
+    }
+
+    /// Gets whether the inventory has no pieces.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Gets an iterator over the denominations with pieces, by currency and value.
+    pub fn iter(&self) -> impl Iterator<Item = InventoryEntry> + '_ {
+        self.counts
+            .iter()
+            .map(|(&(currency, value), &count)| InventoryEntry {
+                currency,
+                value,
+                count,
+            })
+    }
+
+    /// Gets the currencies with pieces.
+    pub fn currencies(&self) -> impl Iterator<Item = Currency> + '_ {
+        let mut last = None;
+        self.counts.keys().filter_map(move |&(currency, _)| {
+            (last.replace(currency) != Some(currency)).then_some(currency)
+        })
+    }
+
+    /// Gets the `(value, count)` pairs of a currency, by ascending value.
+    pub fn denominations(&self, currency: Currency) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.counts
+            .range((currency, 0)..=(currency, u32::MAX))
+            .map(|(&(_, value), &count)| (value, count))
+    }
+
+    /// Adds pieces of a denomination.
+    ///
+    /// If the currency has denomination data, the value must be a denomination of the currency.
+    pub fn deposit(
+        &mut self,
+        currency: Currency,
+        value: u32,
+        count: u32,
+    ) -> Result<(), InventoryError> {
+        let known = currency.denominations();
+        if value == 0 || known.is_some_and(|k| !k.iter().any(|d| d.value == value)) {
+            return Err(InventoryError::InvalidDenomination(currency, value));
+        }
+        if count == 0 {
+            return Ok(());
+        }
+
+        let entry = self.counts.entry((currency, value)).or_insert(0);
+        *entry = entry
+            .checked_add(count)
+            .ok_or(InventoryError::Overflow(currency, value))?;
+        Ok(())
+    }
+
+    /// Removes pieces of a denomination.
+    pub fn withdraw(
+        &mut self,
+        currency: Currency,
+        value: u32,
+        count: u32,
+    ) -> Result<(), InventoryError> {
+        let available = self.count(currency, value);
+        if available < count {
+            return Err(InventoryError::Insufficient(currency, value));
+        }
+
+        if available == count {
+            self.counts.remove(&(currency, value));
+        } else {
+            self.counts.insert((currency, value), available - count);
+        }
+        Ok(())
+    }
+
+    /// Plans a dispense of `amount` from the inventory, without removing pieces.
+    pub fn plan(&self, amount: Money, strategy: Strategy) -> Result<Dispense, InventoryError> {
+        let mut slots = [(0, 0); MAX_DENOMINATIONS];
+        let mut len = 0;
+        for slot in self.denominations(amount.currency()) {
+            *slots
+                .get_mut(len)
+                .ok_or(DispenseError::TooManyDenominations)? = slot;
+            len += 1;
+        }
+        Ok(dispense::dispense(amount, &slots[..len], strategy)?)
+    }
+
+    /// Dispenses `amount`, removing the planned pieces from the inventory.
+    pub fn dispense(
+        &mut self,
+        amount: Money,
+        strategy: Strategy,
+    ) -> Result<Dispense, InventoryError> {
+        let plan = self.plan(amount, strategy)?;
+        for (value, count) in plan.iter() {
+            self.withdraw(plan.currency(), value, count)?;
+        }
+        Ok(plan)
+    }
+
+    /// Gets the total of a currency.
+    pub fn total(&self, currency: Currency) -> Money {
+        let amount = self
+            .denominations(currency)
+            .map(|(value, count)| value as i128 * count as i128)
+            .sum();
+        Money::from_minor(currency, amount)
+    }
+
+    /// Gets the totals of the currencies with pieces.
+    pub fn totals(&self) -> impl Iterator<Item = Money> + '_ {
+        self.currencies().map(|currency| self.total(currency))
+    }
+
+    /// Compares the totals against `expected` totals, returning the currencies that differ.
+    ///
+    /// Currencies missing from `expected` are expected to be empty, and repeated currencies in
+    /// `expected` are added up, saturating at the bounds of `i128`. Inventory totals are far
+    /// below those bounds, so a saturated expected total is always reported as a discrepancy.
+    pub fn reconcile(&self, expected: &[Money]) -> Vec<Discrepancy> {
+        let mut totals: BTreeMap<Currency, (i128, i128)> = self
+            .totals()
+            .map(|total| (total.currency(), (0, total.amount())))
+            .collect();
+        for money in expected {
+            let total = &mut totals.entry(money.currency()).or_insert((0, 0)).0;
+            *total = total.saturating_add(money.amount());
+        }
+
+        totals
+            .into_iter()
+            .filter(|(_, (expected, actual))| expected != actual)
+            .map(|(currency, (expected, actual))| Discrepancy {
+                expected: Money::from_minor(currency, expected),
+                actual: Money::from_minor(currency, actual),
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<InventoryEntry> for Result<CashInventory, InventoryError> {
+    fn from_iter<I: IntoIterator<Item = InventoryEntry>>(iter: I) -> Self {
+        let mut inventory = CashInventory::new();
+        for entry in iter {
+            inventory.deposit(entry.currency, entry.value, entry.count)?;
+        }
+        Ok(inventory)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CashInventory {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CashInventory {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<InventoryEntry>::deserialize(deserializer)?
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd(amount: i128) -> Money {
+        Money::from_minor(Currency::USD, amount)
+    }
+
+    #[test]
+    fn test_deposit_withdraw() {
+        let mut inventory = CashInventory::new();
+        assert!(inventory.is_empty());
+
+        inventory.deposit(Currency::USD, 100, 10).unwrap();
+        inventory.deposit(Currency::USD, 25, 4).unwrap();
+        inventory.deposit(Currency::EUR, 500, 2).unwrap();
+        inventory.deposit(Currency::USD, 100, 5).unwrap();
+        assert_eq!(inventory.count(Currency::USD, 100), 15);
+        assert!(inventory
+            .denominations(Currency::USD)
+            .eq([(25, 4), (100, 15)]));
+        // by numeric code
+        assert!(inventory.currencies().eq([Currency::USD, Currency::EUR]));
+        assert!(inventory
+            .totals()
+            .eq([usd(1600), Money::from_minor(Currency::EUR, 1000)]));
+
+        assert_eq!(
+            inventory.deposit(Currency::USD, 300, 1),
+            Err(InventoryError::InvalidDenomination(Currency::USD, 300))
+        );
+        assert_eq!(
+            inventory.deposit(Currency::USD, 100, u32::MAX),
+            Err(InventoryError::Overflow(Currency::USD, 100))
+        );
+        assert_eq!(
+            inventory.withdraw(Currency::USD, 25, 5),
+            Err(InventoryError::Insufficient(Currency::USD, 25))
+        );
+
+        inventory.withdraw(Currency::USD, 25, 4).unwrap();
+        assert!(inventory.denominations(Currency::USD).eq([(100, 15)]));
+    }
+
+    #[test]
+    fn test_dispense() {
+        let mut inventory = CashInventory::new();
+        inventory.deposit(Currency::USD, 25, 3).unwrap();
+        inventory.deposit(Currency::USD, 10, 5).unwrap();
+
+        let plan = inventory.dispense(usd(30), Strategy::FewestPieces).unwrap();
+        assert!(plan.iter().eq([(10, 3)]));
+        assert_eq!(inventory.count(Currency::USD, 10), 2);
+        assert_eq!(inventory.total(Currency::USD), usd(95));
+
+        assert_eq!(
+            inventory.dispense(usd(15), Strategy::FewestPieces),
+            Err(InventoryError::Dispense(DispenseError::Insufficient))
+        );
+        assert_eq!(inventory.total(Currency::USD), usd(95));
+        assert_eq!(
+            inventory.dispense(Money::from_minor(Currency::EUR, 1), Strategy::FewestPieces),
+            Err(InventoryError::Dispense(DispenseError::Insufficient))
+        );
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let mut inventory = CashInventory::new();
+        inventory.deposit(Currency::USD, 2000, 2).unwrap();
+        inventory.deposit(Currency::GBP, 1000, 1).unwrap();
+
+        assert!(inventory
+            .reconcile(&[usd(4000), Money::from_minor(Currency::GBP, 1000)])
+            .is_empty());
+        assert!(inventory
+            .reconcile(&[usd(3000), usd(1000), Money::from_minor(Currency::GBP, 1000)])
+            .is_empty());
+
+        let discrepancies =
+            inventory.reconcile(&[usd(5000), Money::from_minor(Currency::EUR, 500)]);
+        assert_eq!(discrepancies.len(), 3);
+        let usd_diff = discrepancies
+            .iter()
+            .find(|d| d.currency() == Currency::USD)
+            .unwrap();
+        assert_eq!(usd_diff.difference(), usd(-1000));
+        assert!(discrepancies.iter().any(|d| d.currency() == Currency::GBP
+            && d.difference() == Money::from_minor(Currency::GBP, 1000)));
+        assert!(discrepancies
+            .iter()
+            .any(|d| d.currency() == Currency::EUR
+                && d.actual == Money::from_minor(Currency::EUR, 0)));
+
+        // extreme expected totals saturate instead of overflowing
+        let discrepancies = inventory.reconcile(&[
+            usd(i128::MAX),
+            usd(1),
+            Money::from_minor(Currency::GBP, i128::MIN),
+        ]);
+        assert_eq!(discrepancies.len(), 2);
+        assert_eq!(
+            discrepancies[0].expected,
+            Money::from_minor(Currency::GBP, i128::MIN)
+        );
+        assert_eq!(
+            discrepancies[0].difference(),
+            Money::from_minor(Currency::GBP, i128::MAX)
+        );
+        assert_eq!(discrepancies[1].expected, usd(i128::MAX));
+        assert_eq!(discrepancies[1].difference(), usd(4000 - i128::MAX));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let mut inventory = CashInventory::new();
+        inventory.deposit(Currency::USD, 2000, 2).unwrap();
+        inventory.deposit(Currency::JPY, 1000, 3).unwrap();
+
+        let json = serde_json::to_string(&inventory).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"currency":"JPY","value":1000,"count":3},{"currency":"USD","value":2000,"count":2}]"#
+        );
+        assert_eq!(
+            serde_json::from_str::<CashInventory>(&json).unwrap(),
+            inventory
+        );
+        assert!(serde_json::from_str::<CashInventory>(
+            r#"[{"currency":"USD","value":300,"count":1}]"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,516 @@
+//! ISO 20022 currency codes and currency amounts, as used by e.g. `pain.001`, `pacs.008` and
+//! `camt.053` messages.
+//!
+//! Amounts are elements with a `Ccy` attribute, e.g. `<InstdAmt Ccy="EUR">123.45</InstdAmt>`:
+//!
+//! - [ActiveCurrencyAndAmount] requires an `ActiveCurrencyCode`, a code currently in ISO 4217.
+//! - [ActiveOrHistoricCurrencyAndAmount] also accepts withdrawn codes listed in [HISTORIC].
+//!
+//! Both follow the schema facets of the amount (`minInclusive` 0, `totalDigits` 18,
+//! `fractionDigits` 5) and the `CurrencyAmount` rule, which limits the decimal digits to the minor
+//! units of an active currency.
+//!
+//! With the `serde` feature, amounts map to the attribute and text names used by `quick-xml`
+//! (`@Ccy` and `$text`), and with the `quick-xml` feature, [from_xml] and [to_xml] read and write
+//! amount elements directly.
+//!
+//! ```
+//! use currency_iso4217::iso20022::{ActiveCurrencyAndAmount, ActiveOrHistoricCurrencyAndAmount};
+//! use currency_iso4217::{Currency, Money};
+//!
+//! let amount = ActiveCurrencyAndAmount::parse("EUR", "123.4").unwrap();
+//! assert_eq!(amount.to_money(), Money::from_minor(Currency::EUR, 12340));
+//! assert_eq!(amount.amount().to_string(), "123.40");
+//!
+//! assert!(ActiveCurrencyAndAmount::parse("DEM", "10").is_err());
+//! assert!(ActiveOrHistoricCurrencyAndAmount::parse("DEM", "10").is_ok());
+//! ```
+
+use crate::std::{fmt, str::FromStr};
+use crate::{Currency, Decimal, HistoricCurrency, Money, HISTORIC};
+
+/// Largest number of digits of an amount (`totalDigits`).
+pub const MAX_TOTAL_DIGITS: u32 = 18;
+/// Largest number of decimal digits of an amount (`fractionDigits`).
+pub const MAX_FRACTION_DIGITS: u8 = 5;
+
+/// Errors returned when validating ISO 20022 currency codes and amounts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Iso20022Error {
+    /// The code is not three upper-case letters.
+    InvalidCode,
+    /// The code is neither an active nor a historic currency.
+    UnknownCurrency,
+    /// The code is a historic currency where an active one is required.
+    NotActive(&'static HistoricCurrency),
+    /// The amount is not a decimal number.
+    InvalidAmount,
+    /// The amount is negative.
+    Negative,
+    /// The amount has more than [MAX_TOTAL_DIGITS] digits.
+    TotalDigits,
+    /// The amount has more than [MAX_FRACTION_DIGITS] decimal digits.
+    FractionDigits,
+    /// The amount has more decimal digits than the minor units of the currency.
+    MinorUnits(Currency),
+}
+
+impl fmt::Display for Iso20022Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCode => write!(f, "currency code must be three upper-case letters"),
+            Self::UnknownCurrency => write!(f, "unknown currency code"),
+            Self::NotActive(h) => write!(f, "currency {} is not active", h.code),
+            Self::InvalidAmount => write!(f, "invalid amount"),
+            Self::Negative => write!(f, "amount must not be negative"),
+            Self::TotalDigits => write!(f, "amount exceeds {MAX_TOTAL_DIGITS} digits"),
+            Self::FractionDigits => {
+                write!(f, "amount exceeds {MAX_FRACTION_DIGITS} decimal digits")
+            }
+            Self::MinorUnits(c) => write!(
+                f,
+                "amount exceeds {} decimal digits allowed for {}",
+                c.exponent(),
+                c.alpha()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Iso20022Error {}
+
+/// Parses an `ActiveCurrencyCode`: a code currently in ISO 4217.
+pub fn parse_active_code(code: &str) -> Result<Currency, Iso20022Error> {
+    match code.parse()? {
+        ActiveOrHistoricCurrencyCode::Active(currency) => Ok(currency),
+        ActiveOrHistoricCurrencyCode::Historic(historic) => Err(Iso20022Error::NotActive(historic)),
+    }
+}
+
+/// An `ActiveOrHistoricCurrencyCode`: a code currently in ISO 4217, or withdrawn from it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ActiveOrHistoricCurrencyCode {
+    /// A currency currently in ISO 4217.
+    Active(Currency),
+    /// A currency withdrawn from ISO 4217.
+    Historic(&'static HistoricCurrency),
+}
+
+impl ActiveOrHistoricCurrencyCode {
+    /// Gets the alphabetic code.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::Active(currency) => currency.alpha(),
+            Self::Historic(historic) => historic.code,
+        }
+    }
+
+    /// Gets the active [Currency], `None` if the currency is historic.
+    pub const fn active(&self) -> Option<Currency> {
+        match self {
+            Self::Active(currency) => Some(*currency),
+            Self::Historic(_) => None,
+        }
+    }
+}
+
+impl From<Currency> for ActiveOrHistoricCurrencyCode {
+    fn from(val: Currency) -> Self {
+        Self::Active(val)
+    }
+}
+
+impl FromStr for ActiveOrHistoricCurrencyCode {
+    type Err = Iso20022Error;
+
+    /// Parses a code of exactly three upper-case letters, preferring the active currency.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code: [u8; 3] = s
+            .as_bytes()
+            .try_into()
+            .map_err(|_| Iso20022Error::InvalidCode)?;
+        if !code.iter().all(u8::is_ascii_uppercase) {
+            return Err(Iso20022Error::InvalidCode);
+        }
+
+        if let Some(currency) = Currency::from_alpha(&code) {
+            Ok(Self::Active(currency))
+        } else {
+            HISTORIC
+                .iter()
+                .find(|h| h.code == s)
+                .map(Self::Historic)
+                .ok_or(Iso20022Error::UnknownCurrency)
+        }
+    }
+}
+
+impl fmt::Display for ActiveOrHistoricCurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Checks the schema facets of an amount, and the minor units of an active currency, returning
+/// the amount with trailing fractional zeros removed.
+fn check_amount(amount: Decimal, currency: Option<Currency>) -> Result<Decimal, Iso20022Error> {
+    let amount = amount.normalize();
+    if amount.is_negative() {
+        return Err(Iso20022Error::Negative);
+    }
+    if amount.scale() > MAX_FRACTION_DIGITS {
+        return Err(Iso20022Error::FractionDigits);
+    }
+    if amount.mantissa().checked_ilog10().unwrap_or(0) + 1 > MAX_TOTAL_DIGITS {
+        return Err(Iso20022Error::TotalDigits);
+    }
+    match currency {
+        Some(c) if amount.scale() > c.exponent() => Err(Iso20022Error::MinorUnits(c)),
+        _ => Ok(amount),
+    }
+}
+
+fn parse_amount(amount: &str) -> Result<Decimal, Iso20022Error> {
+    amount.parse().map_err(|_| Iso20022Error::InvalidAmount)
+}
+
+/// An `ActiveCurrencyAndAmount`: a non-negative amount in a currency currently in ISO 4217.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ActiveCurrencyAndAmount {
+    currency: Currency,
+    amount: Decimal,
+}
+
+impl ActiveCurrencyAndAmount {
+    /// Creates a new [ActiveCurrencyAndAmount], validating the amount.
+    pub fn new(currency: Currency, amount: Decimal) -> Result<Self, Iso20022Error> {
+        let amount = check_amount(amount, Some(currency))?;
+        Ok(Self {
+            currency,
+            // cannot fail, the scale is at most the exponent
+            amount: amount.rescale_exact(currency.exponent()).unwrap_or(amount),
+        })
+    }
+
+    /// Creates a new [ActiveCurrencyAndAmount] from an amount of [Money].
+    pub fn from_money(money: Money) -> Result<Self, Iso20022Error> {
+        Self::new(money.currency(), money.to_decimal())
+    }
+
+    /// Parses the `Ccy` attribute and the amount text of an element.
+    pub fn parse(ccy: &str, amount: &str) -> Result<Self, Iso20022Error> {
+        Self::new(parse_active_code(ccy)?, parse_amount(amount)?)
+    }
+
+    /// Gets the currency.
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Gets the amount, with the minor units of the currency as scale.
+    pub const fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Gets the amount as [Money].
+    pub const fn to_money(&self) -> Money {
+        Money::from_minor(self.currency, self.amount.mantissa())
+    }
+}
+
+impl TryFrom<Money> for ActiveCurrencyAndAmount {
+    type Error = Iso20022Error;
+
+    fn try_from(val: Money) -> Result<Self, Self::Error> {
+        Self::from_money(val)
+    }
+}
+
+/// An `ActiveOrHistoricCurrencyAndAmount`: a non-negative amount in a currency currently in ISO
+/// 4217, or withdrawn from it.
+///
+/// The minor units are only checked for active currencies, as [HISTORIC] does not record them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ActiveOrHistoricCurrencyAndAmount {
+    currency: ActiveOrHistoricCurrencyCode,
+    amount: Decimal,
+}
+
+impl ActiveOrHistoricCurrencyAndAmount {
+    /// Creates a new [ActiveOrHistoricCurrencyAndAmount], validating the amount.
+    pub fn new(
+        currency: ActiveOrHistoricCurrencyCode,
+        amount: Decimal,
+    ) -> Result<Self, Iso20022Error> {
+        match currency {
+            ActiveOrHistoricCurrencyCode::Active(c) => {
+                ActiveCurrencyAndAmount::new(c, amount).map(Self::from)
+            }
+            ActiveOrHistoricCurrencyCode::Historic(_) => Ok(Self {
+                currency,
+                amount: check_amount(amount, None)?,
+            }),
+        }
+    }
+
+    /// Parses the `Ccy` attribute and the amount text of an element.
+    pub fn parse(ccy: &str, amount: &str) -> Result<Self, Iso20022Error> {
+        Self::new(ccy.parse()?, parse_amount(amount)?)
+    }
+
+    /// Gets the currency.
+    pub const fn currency(&self) -> ActiveOrHistoricCurrencyCode {
+        self.currency
+    }
+
+    /// Gets the amount.
+    pub const fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Gets the amount as [Money], `None` if the currency is historic.
+    pub const fn to_money(&self) -> Option<Money> {
+        match self.currency {
+            ActiveOrHistoricCurrencyCode::Active(c) => {
+                Some(Money::from_minor(c, self.amount.mantissa()))
+            }
+            ActiveOrHistoricCurrencyCode::Historic(_) => None,
+        }
+    }
+}
+
+impl From<ActiveCurrencyAndAmount> for ActiveOrHistoricCurrencyAndAmount {
+    fn from(val: ActiveCurrencyAndAmount) -> Self {
+        Self {
+            currency: val.currency.into(),
+            amount: val.amount,
+        }
+    }
+}
+
+impl TryFrom<ActiveOrHistoricCurrencyAndAmount> for ActiveCurrencyAndAmount {
+    type Error = Iso20022Error;
+
+    fn try_from(val: ActiveOrHistoricCurrencyAndAmount) -> Result<Self, Self::Error> {
+        match val.currency {
+            ActiveOrHistoricCurrencyCode::Active(currency) => Ok(Self {
+                currency,
+                amount: val.amount,
+            }),
+            ActiveOrHistoricCurrencyCode::Historic(h) => Err(Iso20022Error::NotActive(h)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// An amount element, with the attribute and text names of `quick-xml`.
+    #[derive(Deserialize, Serialize)]
+    struct Element {
+        #[serde(rename = "@Ccy")]
+        ccy: String,
+        #[serde(rename = "$text")]
+        amount: String,
+    }
+
+    impl Serialize for ActiveOrHistoricCurrencyCode {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.code())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ActiveOrHistoricCurrencyCode {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            String::deserialize(deserializer)?
+                .parse()
+                .map_err(D::Error::custom)
+        }
+    }
+
+    impl Serialize for ActiveCurrencyAndAmount {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            ActiveOrHistoricCurrencyAndAmount::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ActiveCurrencyAndAmount {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let el = Element::deserialize(deserializer)?;
+            Self::parse(&el.ccy, el.amount.trim()).map_err(D::Error::custom)
+        }
+    }
+
+    impl Serialize for ActiveOrHistoricCurrencyAndAmount {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Element {
+                ccy: self.currency.code().to_string(),
+                amount: self.amount.to_string(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ActiveOrHistoricCurrencyAndAmount {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let el = Element::deserialize(deserializer)?;
+            Self::parse(&el.ccy, el.amount.trim()).map_err(D::Error::custom)
+        }
+    }
+}
+
+/// Reads an amount element, e.g. `<InstdAmt Ccy="EUR">123.45</InstdAmt>`.
+#[cfg(feature = "quick-xml")]
+pub fn from_xml<T: serde::de::DeserializeOwned>(xml: &str) -> Result<T, quick_xml::DeError> {
+    quick_xml::de::from_str(xml)
+}
+
+/// Writes an amount element with the given tag, e.g. `<InstdAmt Ccy="EUR">123.45</InstdAmt>`.
+#[cfg(feature = "quick-xml")]
+pub fn to_xml<T: serde::Serialize>(
+    tag: &str,
+    value: &T,
+) -> Result<alloc::string::String, quick_xml::SeError> {
+    quick_xml::se::to_string_with_root(tag, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_codes() {
+        assert_eq!(parse_active_code("EUR"), Ok(Currency::EUR));
+        assert_eq!(parse_active_code("eur"), Err(Iso20022Error::InvalidCode));
+        assert_eq!(parse_active_code("EU"), Err(Iso20022Error::InvalidCode));
+        assert_eq!(
+            parse_active_code("QQQ"),
+            Err(Iso20022Error::UnknownCurrency)
+        );
+        assert!(matches!(
+            parse_active_code("HRK"),
+            Err(Iso20022Error::NotActive(h)) if h.code == "HRK"
+        ));
+
+        let hrk: ActiveOrHistoricCurrencyCode = "HRK".parse().unwrap();
+        assert_eq!(hrk.code(), "HRK");
+        assert_eq!(hrk.active(), None);
+        assert_eq!(hrk.to_string(), "HRK");
+        assert_eq!(
+            "USD".parse::<ActiveOrHistoricCurrencyCode>(),
+            Ok(ActiveOrHistoricCurrencyCode::Active(Currency::USD))
+        );
+    }
+
+    #[test]
+    fn test_active_amount() {
+        let amount = ActiveCurrencyAndAmount::parse("USD", "1234.5").unwrap();
+        assert_eq!(amount.currency(), Currency::USD);
+        assert_eq!(amount.amount().to_string(), "1234.50");
+        assert_eq!(amount.to_money(), Money::from_minor(Currency::USD, 123450));
+
+        // trailing zeros are not significant
+        assert_eq!(
+            ActiveCurrencyAndAmount::parse("JPY", "1500.000")
+                .unwrap()
+                .to_money(),
+            Money::from_minor(Currency::JPY, 1500)
+        );
+        assert_eq!(
+            ActiveCurrencyAndAmount::parse("JPY", "1500.5"),
+            Err(Iso20022Error::MinorUnits(Currency::JPY))
+        );
+        assert_eq!(
+            ActiveCurrencyAndAmount::parse("USD", "-1"),
+            Err(Iso20022Error::Negative)
+        );
+        assert_eq!(
+            ActiveCurrencyAndAmount::parse("USD", "1,5"),
+            Err(Iso20022Error::InvalidAmount)
+        );
+        assert_eq!(
+            ActiveCurrencyAndAmount::parse("USD", "12345678901234567.89"),
+            Err(Iso20022Error::TotalDigits)
+        );
+        assert!(ActiveCurrencyAndAmount::parse("USD", "1234567890123456.78").is_ok());
+        assert!(matches!(
+            ActiveCurrencyAndAmount::parse("DEM", "1"),
+            Err(Iso20022Error::NotActive(_))
+        ));
+
+        let money = Money::from_minor(Currency::KWD, 1234);
+        assert_eq!(
+            ActiveCurrencyAndAmount::try_from(money).unwrap().to_money(),
+            money
+        );
+    }
+
+    #[test]
+    fn test_historic_amount() {
+        let amount = ActiveOrHistoricCurrencyAndAmount::parse("DEM", "10.12345").unwrap();
+        assert_eq!(amount.currency().code(), "DEM");
+        assert_eq!(amount.to_money(), None);
+        assert_eq!(
+            ActiveOrHistoricCurrencyAndAmount::parse("DEM", "10.123456"),
+            Err(Iso20022Error::FractionDigits)
+        );
+        assert!(matches!(
+            ActiveCurrencyAndAmount::try_from(amount),
+            Err(Iso20022Error::NotActive(_))
+        ));
+
+        let amount = ActiveOrHistoricCurrencyAndAmount::parse("EUR", "5").unwrap();
+        assert_eq!(amount.amount().to_string(), "5.00");
+        assert_eq!(
+            ActiveOrHistoricCurrencyAndAmount::parse("EUR", "5.001"),
+            Err(Iso20022Error::MinorUnits(Currency::EUR))
+        );
+        assert_eq!(
+            ActiveCurrencyAndAmount::try_from(amount).map(|a| a.to_money()),
+            Ok(Money::from_minor(Currency::EUR, 500))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let amount = ActiveCurrencyAndAmount::parse("EUR", "123.45").unwrap();
+        let json = serde_json::to_string(&amount).unwrap();
+        assert_eq!(json, r#"{"@Ccy":"EUR","$text":"123.45"}"#);
+        assert_eq!(
+            serde_json::from_str::<ActiveCurrencyAndAmount>(&json).unwrap(),
+            amount
+        );
+        assert!(serde_json::from_str::<ActiveCurrencyAndAmount>(
+            r#"{"@Ccy":"EUR","$text":"123.456"}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<ActiveOrHistoricCurrencyAndAmount>(
+            r#"{"@Ccy":"FRF","$text":"100"}"#
+        )
+        .is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "quick-xml")]
+    fn test_xml() {
+        let amount: ActiveCurrencyAndAmount =
+            from_xml(r#"<InstdAmt Ccy="EUR">123.45</InstdAmt>"#).unwrap();
+        assert_eq!(amount.to_money(), Money::from_minor(Currency::EUR, 12345));
+        assert_eq!(
+            to_xml("InstdAmt", &amount).unwrap(),
+            r#"<InstdAmt Ccy="EUR">123.45</InstdAmt>"#
+        );
+
+        assert!(from_xml::<ActiveCurrencyAndAmount>(r#"<Amt Ccy="ITL">1000</Amt>"#).is_err());
+        let historic: ActiveOrHistoricCurrencyAndAmount =
+            from_xml(r#"<Amt Ccy="ITL">1000</Amt>"#).unwrap();
+        assert_eq!(historic.currency().code(), "ITL");
+        assert!(from_xml::<ActiveCurrencyAndAmount>(r#"<Amt Ccy="JPY">1.5</Amt>"#).is_err());
+    }
+}
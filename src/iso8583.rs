@@ -0,0 +1,338 @@
+//! ISO 8583 currency code fields (49, 50 and 51) and their amount fields (4, 5 and 6).
+//!
+//! Currency codes are `n3` numeric fields, and amounts `n12` numeric fields in minor units of the
+//! associated currency, so the currency exponent is implied rather than sent. Numeric fields are
+//! encoded as ASCII digits, EBCDIC digits, or packed BCD (left-padded with a zero nibble).
+//!
+//! ```
+//! use currency_iso4217::iso8583::{self, Encoding};
+//! use currency_iso4217::{Currency, Money};
+//!
+//! let mut buf = [0u8; 6];
+//! let len = iso8583::encode_currency(Currency::USD, Encoding::Ascii, &mut buf).unwrap();
+//! assert_eq!(&buf[..len], b"840");
+//!
+//! let usd = Money::from_minor(Currency::USD, 12345);
+//! let len = iso8583::encode_amount(usd, Encoding::Bcd, &mut buf).unwrap();
+//! assert_eq!(&buf[..len], [0x00, 0x00, 0x00, 0x01, 0x23, 0x45]);
+//! ```
+
+use crate::std::fmt;
+use crate::{Currency, Money};
+
+/// Number of digits in a currency code field.
+pub const CURRENCY_DIGITS: usize = 3;
+/// Number of digits in an amount field.
+pub const AMOUNT_DIGITS: usize = 12;
+/// Largest amount, in minor units, that fits an amount field.
+pub const MAX_AMOUNT: i128 = 999_999_999_999;
+
+/// ISO 8583 currency code data elements.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Field {
+    /// Field 49: currency code, transaction.
+    Transaction,
+    /// Field 50: currency code, settlement.
+    Settlement,
+    /// Field 51: currency code, cardholder billing.
+    CardholderBilling,
+}
+
+impl Field {
+    /// Gets the data element number of the currency code field, e.g. `49`.
+    pub const fn number(&self) -> u8 {
+        match self {
+            Self::Transaction => 49,
+            Self::Settlement => 50,
+            Self::CardholderBilling => 51,
+        }
+    }
+
+    /// Gets the data element number of the amount field in this currency, e.g. `4`.
+    pub const fn amount_field(&self) -> u8 {
+        match self {
+            Self::Transaction => 4,
+            Self::Settlement => 5,
+            Self::CardholderBilling => 6,
+        }
+    }
+
+    /// Gets the [Field] for a currency code data element number.
+    pub const fn from_number(number: u8) -> Option<Self> {
+        match number {
+            49 => Some(Self::Transaction),
+            50 => Some(Self::Settlement),
+            51 => Some(Self::CardholderBilling),
+            _ => None,
+        }
+    }
+}
+
+/// Encodings of ISO 8583 numeric fields.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Encoding {
+    /// ASCII digits, one per byte.
+    #[default]
+    Ascii,
+    /// EBCDIC digits (`F0`-`F9`), one per byte.
+    Ebcdic,
+    /// Packed BCD, two digits per byte, left-padded with a zero nibble.
+    Bcd,
+}
+
+impl Encoding {
+    /// Gets the encoded length in bytes of a fixed-length numeric field.
+    pub const fn len(&self, digits: usize) -> usize {
+        match self {
+            Self::Ascii | Self::Ebcdic => digits,
+            Self::Bcd => digits.div_ceil(2),
+        }
+    }
+}
+
+/// Errors returned when encoding or decoding ISO 8583 fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Iso8583Error {
+    /// The field has the wrong length.
+    InvalidLength,
+    /// The field contains a byte that is not a digit in the encoding.
+    InvalidDigit,
+    /// The output buffer is too small.
+    BufferTooSmall,
+    /// The numeric code is not a known currency.
+    UnknownCurrency(u16),
+    /// The amount is negative or exceeds [MAX_AMOUNT].
+    AmountOutOfRange,
+}
+
+impl fmt::Display for Iso8583Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "invalid field length"),
+            Self::InvalidDigit => write!(f, "invalid digit"),
+            Self::BufferTooSmall => write!(f, "buffer too small"),
+            Self::UnknownCurrency(code) => write!(f, "unknown numeric currency code {code:03}"),
+            Self::AmountOutOfRange => write!(f, "amount out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Iso8583Error {}
+
+/// Encodes a currency code field, returning the number of bytes written to `buf`.
+pub fn encode_currency(
+    currency: Currency,
+    encoding: Encoding,
+    buf: &mut [u8],
+) -> Result<usize, Iso8583Error> {
+    encode_digits(u32::from(currency) as u64, CURRENCY_DIGITS, encoding, buf)
+}
+
+/// Decodes a currency code field.
+pub fn decode_currency(field: &[u8], encoding: Encoding) -> Result<Currency, Iso8583Error> {
+    let code = decode_digits(field, CURRENCY_DIGITS, encoding)? as u32;
+    Currency::from_numeric(code).ok_or(Iso8583Error::UnknownCurrency(code as u16))
+}
+
+/// Encodes an amount field in minor units of its currency, returning the number of bytes written
+/// to `buf`.
+pub fn encode_amount(
+    money: Money,
+    encoding: Encoding,
+    buf: &mut [u8],
+) -> Result<usize, Iso8583Error> {
+    if !(0..=MAX_AMOUNT).contains(&money.amount()) {
+        return Err(Iso8583Error::AmountOutOfRange);
+    }
+    encode_digits(money.amount() as u64, AMOUNT_DIGITS, encoding, buf)
+}
+
+/// Decodes an amount field, in minor units of the currency from the associated currency code
+/// field.
+pub fn decode_amount(
+    field: &[u8],
+    encoding: Encoding,
+    currency: Currency,
+) -> Result<Money, Iso8583Error> {
+    let amount = decode_digits(field, AMOUNT_DIGITS, encoding)?;
+    Ok(Money::from_minor(currency, amount as i128))
+}
+
+/// Decodes an amount field together with its currency code field.
+pub fn decode_amount_with_currency(
+    amount: &[u8],
+    currency: &[u8],
+    encoding: Encoding,
+) -> Result<Money, Iso8583Error> {
+    decode_amount(amount, encoding, decode_currency(currency, encoding)?)
+}
+
+fn encode_digits(
+    value: u64,
+    digits: usize,
+    encoding: Encoding,
+    buf: &mut [u8],
+) -> Result<usize, Iso8583Error> {
+    let len = encoding.len(digits);
+    let out = buf.get_mut(..len).ok_or(Iso8583Error::BufferTooSmall)?;
+
+    let mut value = value;
+    match encoding {
+        Encoding::Ascii | Encoding::Ebcdic => {
+            let zero = if encoding == Encoding::Ascii {
+                b'0'
+            } else {
+                0xF0
+            };
+            for b in out.iter_mut().rev() {
+                *b = zero + (value % 10) as u8;
+                value /= 10;
+            }
+        }
+        Encoding::Bcd => {
+            for b in out.iter_mut().rev() {
+                *b = ((((value / 10) % 10) << 4) | (value % 10)) as u8;
+                value /= 100;
+            }
+        }
+    }
+
+    if value == 0 {
+        Ok(len)
+    } else {
+        Err(Iso8583Error::AmountOutOfRange)
+    }
+}
+
+fn decode_digits(field: &[u8], digits: usize, encoding: Encoding) -> Result<u64, Iso8583Error> {
+    if field.len() != encoding.len(digits) {
+        return Err(Iso8583Error::InvalidLength);
+    }
+
+    let digit = |d: u8| match encoding {
+        Encoding::Ascii if d.is_ascii_digit() => Ok((d - b'0') as u64),
+        Encoding::Ebcdic if (0xF0..=0xF9).contains(&d) => Ok((d - 0xF0) as u64),
+        Encoding::Bcd if d <= 9 => Ok(d as u64),
+        _ => Err(Iso8583Error::InvalidDigit),
+    };
+
+    match encoding {
+        Encoding::Ascii | Encoding::Ebcdic => field
+            .iter()
+            .try_fold(0u64, |acc, &b| Ok(acc * 10 + digit(b)?)),
+        Encoding::Bcd => {
+            // an odd number of digits leaves a zero pad nibble
+            if digits % 2 == 1 && field[0] >> 4 != 0 {
+                return Err(Iso8583Error::InvalidDigit);
+            }
+            field.iter().try_fold(0u64, |acc, &b| {
+                Ok(acc * 100 + digit(b >> 4)? * 10 + digit(b & 0x0F)?)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_currency_field() {
+        let mut buf = [0u8; 3];
+        let cases: [(Encoding, &[u8]); 3] = [
+            (Encoding::Ascii, b"978"),
+            (Encoding::Ebcdic, &[0xF9, 0xF7, 0xF8]),
+            (Encoding::Bcd, &[0x09, 0x78]),
+        ];
+
+        for (encoding, expected) in cases {
+            let len = encode_currency(Currency::EUR, encoding, &mut buf).unwrap();
+            assert_eq!(&buf[..len], expected);
+            assert_eq!(decode_currency(expected, encoding), Ok(Currency::EUR));
+        }
+
+        assert_eq!(
+            encode_currency(Currency::ALL, Encoding::Ascii, &mut buf),
+            Ok(3)
+        );
+        assert_eq!(&buf, b"008");
+
+        assert_eq!(
+            encode_currency(Currency::EUR, Encoding::Ascii, &mut buf[..2]),
+            Err(Iso8583Error::BufferTooSmall)
+        );
+        assert_eq!(
+            decode_currency(b"97", Encoding::Ascii),
+            Err(Iso8583Error::InvalidLength)
+        );
+        assert_eq!(
+            decode_currency(b"9A8", Encoding::Ascii),
+            Err(Iso8583Error::InvalidDigit)
+        );
+        assert_eq!(
+            decode_currency(b"978", Encoding::Ebcdic),
+            Err(Iso8583Error::InvalidDigit)
+        );
+        assert_eq!(
+            decode_currency(&[0x19, 0x78], Encoding::Bcd),
+            Err(Iso8583Error::InvalidDigit)
+        );
+        assert_eq!(
+            decode_currency(b"001", Encoding::Ascii),
+            Err(Iso8583Error::UnknownCurrency(1))
+        );
+    }
+
+    #[test]
+    fn test_amount_field() {
+        let mut buf = [0u8; AMOUNT_DIGITS];
+
+        let jpy = Money::from_minor(Currency::JPY, 1500);
+        let len = encode_amount(jpy, Encoding::Ascii, &mut buf).unwrap();
+        assert_eq!(&buf[..len], b"000000001500");
+
+        let len = encode_amount(jpy, Encoding::Ebcdic, &mut buf).unwrap();
+        assert_eq!(
+            decode_amount(&buf[..len], Encoding::Ebcdic, Currency::JPY),
+            Ok(jpy)
+        );
+
+        // the same field value is 15.00 USD and 1.500 KWD
+        assert_eq!(
+            decode_amount(b"000000001500", Encoding::Ascii, Currency::USD)
+                .unwrap()
+                .to_decimal()
+                .to_string(),
+            "15.00"
+        );
+        assert_eq!(
+            decode_amount_with_currency(&[0, 0, 0, 0, 0x15, 0x00], &[0x04, 0x14], Encoding::Bcd)
+                .unwrap()
+                .to_string(),
+            "1.500 KWD"
+        );
+
+        assert_eq!(
+            encode_amount(
+                Money::from_minor(Currency::USD, MAX_AMOUNT + 1),
+                Encoding::Bcd,
+                &mut buf
+            ),
+            Err(Iso8583Error::AmountOutOfRange)
+        );
+        assert_eq!(
+            encode_amount(
+                Money::from_minor(Currency::USD, -1),
+                Encoding::Ascii,
+                &mut buf
+            ),
+            Err(Iso8583Error::AmountOutOfRange)
+        );
+
+        assert_eq!(Field::from_number(50), Some(Field::Settlement));
+        assert_eq!(Field::CardholderBilling.amount_field(), 6);
+        assert_eq!(Field::Transaction.number(), 49);
+    }
+}
@@ -0,0 +1,341 @@
+//! Currency-tagged monetary amounts.
+
+use crate::std::cmp::Ordering;
+use crate::std::fmt;
+use crate::{Currency, Decimal, DecimalError};
+
+/// Rounding strategy used when an amount cannot be represented exactly.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Rounding {
+    /// Round toward zero (truncate).
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceiling,
+    /// Round to nearest, ties away from zero.
+    HalfUp,
+    /// Round to nearest, ties toward zero.
+    HalfDown,
+    /// Round to nearest, ties to the even neighbour (banker's rounding).
+    #[default]
+    HalfEven,
+}
+
+impl Rounding {
+    /// Divides `num` by `den`, rounding the quotient with this strategy.
+    ///
+    /// Returns `None` if `den` is zero, or on overflow.
+    pub fn div(self, num: i128, den: i128) -> Option<i128> {
+        if den == 0 || (num == i128::MIN && den == -1) {
+            return None;
+        }
+
+        let (quot, rem) = (num / den, num % den);
+        if rem == 0 {
+            return Some(quot);
+        }
+
+        let negative = (num < 0) != (den < 0);
+        // compare twice the remainder against the divisor to find the tie point
+        let twice = rem.unsigned_abs().saturating_mul(2);
+        let half = twice.cmp(&den.unsigned_abs());
+        let away = match self {
+            Self::Down => false,
+            Self::Up => true,
+            Self::Floor => negative,
+            Self::Ceiling => !negative,
+            Self::HalfUp => !matches!(half, Ordering::Less),
+            Self::HalfDown => matches!(half, Ordering::Greater),
+            Self::HalfEven => match half {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => quot % 2 != 0,
+            },
+        };
+
+        if !away {
+            Some(quot)
+        } else if negative {
+            quot.checked_sub(1)
+        } else {
+            quot.checked_add(1)
+        }
+    }
+}
+
+/// A unit that amounts of money are expressed in: an ISO 4217 [Currency], or a custom currency
+/// such as [AnyCurrency](crate::AnyCurrency).
+///
+/// Implemented by the currency types, and references to them, so that [Money], decimal
+/// rounding and formatting, conversions and [words](crate::words) work with any of them.
+pub trait CurrencyUnit {
+    /// Gets the code, e.g. `USD` or `BTC`.
+    fn code(&self) -> &str;
+
+    /// Gets the decimal exponent used for amounts, see [Currency::exponent].
+    fn exponent(&self) -> u8;
+
+    /// Gets the ISO 4217 [Currency], `None` for custom currencies.
+    fn iso(&self) -> Option<Currency> {
+        None
+    }
+}
+
+impl CurrencyUnit for Currency {
+    fn code(&self) -> &str {
+        self.alpha()
+    }
+
+    fn exponent(&self) -> u8 {
+        Currency::exponent(self)
+    }
+
+    fn iso(&self) -> Option<Currency> {
+        Some(*self)
+    }
+}
+
+impl<C: CurrencyUnit + ?Sized> CurrencyUnit for &C {
+    fn code(&self) -> &str {
+        (**self).code()
+    }
+
+    fn exponent(&self) -> u8 {
+        (**self).exponent()
+    }
+
+    fn iso(&self) -> Option<Currency> {
+        (**self).iso()
+    }
+}
+
+/// An amount of money in a [Currency], stored as an integer number of minor units.
+///
+/// Currencies without applicable minor units (e.g. [Currency::XAU]) use an exponent of zero.
+/// Amounts in other [CurrencyUnit]s, e.g. [AnyMoney](crate::AnyMoney), share the same API.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Money<C = Currency> {
+    currency: C,
+    amount: i128,
+}
+
+impl<C> Money<C> {
+    /// Creates a new [Money] from an amount in minor units, e.g. `12345` for `123.45 USD`.
+    pub const fn from_minor(currency: C, amount: i128) -> Self {
+        Self { currency, amount }
+    }
+
+    /// Gets the amount in minor units.
+    pub const fn amount(&self) -> i128 {
+        self.amount
+    }
+
+    /// Gets the currency and the amount in minor units.
+    pub const fn parts(&self) -> (&C, i128) {
+        (&self.currency, self.amount)
+    }
+}
+
+impl Money {
+    /// Gets the [Currency].
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+}
+
+impl<C: CurrencyUnit> Money<C> {
+    /// Creates a new [Money] from an amount in major units, e.g. `123` for `123.00 USD`.
+    ///
+    /// Returns `None` on overflow.
+    pub fn from_major(currency: C, amount: i128) -> Option<Self> {
+        amount
+            .checked_mul(pow10(currency.exponent()))
+            .map(|amount| Self { currency, amount })
+    }
+
+    /// Gets the decimal exponent of the currency, see [CurrencyUnit::exponent].
+    pub fn exponent(&self) -> u8 {
+        self.currency.exponent()
+    }
+
+    /// Creates a new [Money] from a decimal amount in major units, rounding to the minor units of
+    /// the currency.
+    ///
+    /// Returns `None` on overflow.
+    pub fn from_decimal(currency: C, value: Decimal, rounding: Rounding) -> Option<Self> {
+        value
+            .round_for(&currency, rounding)
+            .map(|v| Self::from_minor(currency, v.mantissa()))
+    }
+
+    /// Creates a new [Money] from a decimal amount in major units.
+    ///
+    /// Fails with [DecimalError::Precision] if the value has more decimal places than the minor
+    /// units of the currency, e.g. `1.234` USD.
+    pub fn try_from_decimal(currency: C, value: Decimal) -> Result<Self, DecimalError> {
+        value
+            .rescale_exact(currency.exponent())
+            .map(|v| Self::from_minor(currency, v.mantissa()))
+    }
+
+    /// Creates a new [Money] from a decimal amount in major units, rounding only if `rounding` is
+    /// given.
+    ///
+    /// Without `rounding`, behaves like [try_from_decimal](Self::try_from_decimal).
+    pub fn from_decimal_with(
+        currency: C,
+        value: Decimal,
+        rounding: Option<Rounding>,
+    ) -> Result<Self, DecimalError> {
+        match rounding {
+            Some(rounding) => {
+                Self::from_decimal(currency, value, rounding).ok_or(DecimalError::Overflow)
+            }
+            None => Self::try_from_decimal(currency, value),
+        }
+    }
+
+    /// Gets the amount as a decimal in major units, with the scale of the currency minor units.
+    pub fn to_decimal(&self) -> Decimal {
+        Decimal::from_parts(self.amount, self.currency.exponent())
+    }
+}
+
+impl<C: PartialEq> Money<C> {
+    /// Adds two amounts in the same currency.
+    ///
+    /// Returns `None` if the currencies differ, or on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        if self.currency != rhs.currency {
+            return None;
+        }
+        self.amount
+            .checked_add(rhs.amount)
+            .map(|amount| Self::from_minor(self.currency, amount))
+    }
+
+    /// Subtracts two amounts in the same currency.
+    ///
+    /// Returns `None` if the currencies differ, or on overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        if self.currency != rhs.currency {
+            return None;
+        }
+        self.amount
+            .checked_sub(rhs.amount)
+            .map(|amount| Self::from_minor(self.currency, amount))
+    }
+}
+
+impl<C: CurrencyUnit> fmt::Display for Money<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal(), self.currency.code())
+    }
+}
+
+/// Computes `10^exp` for currency exponents.
+pub(crate) const fn pow10(exp: u8) -> i128 {
+    10i128.pow(exp as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_rounding() {
+        let cases = [
+            // (num, den, Down, Up, Floor, Ceiling, HalfUp, HalfDown, HalfEven)
+            (5, 2, [2, 3, 2, 3, 3, 2, 2]),
+            (7, 2, [3, 4, 3, 4, 4, 3, 4]),
+            (-5, 2, [-2, -3, -3, -2, -3, -2, -2]),
+            (10, 3, [3, 4, 3, 4, 3, 3, 3]),
+            (-20, 3, [-6, -7, -7, -6, -7, -7, -7]),
+            (6, 3, [2, 2, 2, 2, 2, 2, 2]),
+        ];
+        let modes = [
+            Rounding::Down,
+            Rounding::Up,
+            Rounding::Floor,
+            Rounding::Ceiling,
+            Rounding::HalfUp,
+            Rounding::HalfDown,
+            Rounding::HalfEven,
+        ];
+
+        for (num, den, expected) in cases {
+            for (mode, exp) in modes.iter().zip(expected) {
+                assert_eq!(mode.div(num, den), Some(exp), "{num}/{den} {mode:?}");
+            }
+        }
+
+        assert_eq!(Rounding::HalfEven.div(1, 0), None);
+        assert_eq!(Rounding::Down.div(i128::MIN, -1), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Money::from_minor(Currency::USD, 12345).to_string(),
+            "123.45 USD"
+        );
+        assert_eq!(
+            Money::from_minor(Currency::USD, -5).to_string(),
+            "-0.05 USD"
+        );
+        assert_eq!(Money::from_minor(Currency::JPY, 500).to_string(), "500 JPY");
+        assert_eq!(
+            Money::from_minor(Currency::KWD, 1005).to_string(),
+            "1.005 KWD"
+        );
+        assert_eq!(Money::from_minor(Currency::XAU, 3).to_string(), "3 XAU");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Money::from_major(Currency::EUR, 2).unwrap();
+        let b = Money::from_minor(Currency::EUR, 50);
+
+        assert_eq!(
+            a.checked_add(b),
+            Some(Money::from_minor(Currency::EUR, 250))
+        );
+        assert_eq!(
+            a.checked_sub(b),
+            Some(Money::from_minor(Currency::EUR, 150))
+        );
+        assert_eq!(a.checked_add(Money::from_minor(Currency::USD, 1)), None);
+    }
+
+    #[test]
+    fn test_decimal() {
+        let value: Decimal = "1.234".parse().unwrap();
+
+        assert_eq!(
+            Money::try_from_decimal(Currency::USD, value),
+            Err(DecimalError::Precision)
+        );
+        assert_eq!(
+            Money::try_from_decimal(Currency::KWD, value),
+            Ok(Money::from_minor(Currency::KWD, 1234))
+        );
+        assert_eq!(
+            Money::try_from_decimal(Currency::USD, "1.230".parse().unwrap()),
+            Ok(Money::from_minor(Currency::USD, 123))
+        );
+        assert_eq!(
+            Money::from_decimal(Currency::USD, value, Rounding::Up),
+            Some(Money::from_minor(Currency::USD, 124))
+        );
+        assert_eq!(
+            Money::from_minor(Currency::USD, 123).to_decimal(),
+            "1.23".parse().unwrap()
+        );
+    }
+}
@@ -0,0 +1,592 @@
+//! Pluggable exchange rate sources with as-of dates.
+//!
+//! Conversion code is written against the [ExchangeRateProvider] trait, so the rate source can be
+//! swapped without touching it:
+//!
+//! - [ExchangeRates]: a static table, ignoring the date
+//! - [InMemoryProvider]: dated snapshots, e.g. parsed from an ECB reference rate file
+//! - `FileProvider`: snapshots loaded from an ECB-style XML/CSV file (requires `std`)
+//! - [ChainedProvider]: tries several providers in order
+//! - `CachedProvider`: caches another provider's rates for a fixed time (requires `std`)
+//!
+//! ```
+//! use currency_iso4217::{Currency, Date, ExchangeRateProvider, InMemoryProvider, Money, Rounding};
+//!
+//! let csv = "Date,USD,JPY,\n2024-01-16,1.0882,160.95,\n2024-01-15,1.0945,160.33,\n";
+//! let rates = InMemoryProvider::from_ecb_csv(csv).unwrap();
+//! let at = Date::new(2024, 1, 15).unwrap();
+//!
+//! let usd = Money::from_minor(Currency::USD, 10945);
+//! let eur = rates.convert(usd, Currency::EUR, at, Rounding::HalfEven).unwrap();
+//!
+//! assert_eq!(eur, Money::from_minor(Currency::EUR, 10000));
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::rates::{self, parse_code};
+use crate::{Currency, Date, ExchangeRates, Money, Rate, RateError, Rounding};
+
+/// A source of exchange rates, as of a given date.
+pub trait ExchangeRateProvider {
+    /// Gets the rate for `from -> to` in effect at the date `at`.
+    fn rate(&self, from: Currency, to: Currency, at: Date) -> Result<Rate, RateError>;
+
+    /// Converts `money` into the `to` currency at the rate in effect at the date `at`, rounding to
+    /// its minor units.
+    fn convert(
+        &self,
+        money: Money,
+        to: Currency,
+        at: Date,
+        rounding: Rounding,
+    ) -> Result<Money, RateError> {
+        let rate = self.rate(money.currency(), to, at)?;
+        rates::convert(money, to, &rate, rounding)
+    }
+}
+
+impl<P: ExchangeRateProvider + ?Sized> ExchangeRateProvider for &P {
+    fn rate(&self, from: Currency, to: Currency, at: Date) -> Result<Rate, RateError> {
+        (**self).rate(from, to, at)
+    }
+}
+
+impl<P: ExchangeRateProvider + ?Sized> ExchangeRateProvider for Box<P> {
+    fn rate(&self, from: Currency, to: Currency, at: Date) -> Result<Rate, RateError> {
+        (**self).rate(from, to, at)
+    }
+}
+
+impl ExchangeRateProvider for ExchangeRates {
+    /// Gets the rate from the static table, regardless of the date.
+    fn rate(&self, from: Currency, to: Currency, _at: Date) -> Result<Rate, RateError> {
+        ExchangeRates::rate(self, from, to)
+    }
+}
+
+/// Dated snapshots of exchange rates held in memory.
+///
+/// A lookup uses the most recent snapshot published on or before the requested date, like
+/// reference rates that only change on business days.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InMemoryProvider {
+    snapshots: BTreeMap<Date, ExchangeRates>,
+}
+
+impl InMemoryProvider {
+    /// Creates a new, empty [InMemoryProvider].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a snapshot of rates published at `date`, replacing any previous one.
+    pub fn insert(&mut self, date: Date, rates: ExchangeRates) -> Option<ExchangeRates> {
+        self.snapshots.insert(date, rates)
+    }
+
+    /// Inserts a single rate into the snapshot published at `date`.
+    pub fn insert_rate(&mut self, date: Date, from: Currency, to: Currency, rate: Rate) {
+        self.snapshots
+            .entry(date)
+            .or_default()
+            .insert(from, to, rate);
+    }
+
+    /// Gets the snapshot in effect at `date`, if any.
+    pub fn snapshot(&self, at: Date) -> Option<(Date, &ExchangeRates)> {
+        self.snapshots
+            .range(..=at)
+            .next_back()
+            .map(|(&date, rates)| (date, rates))
+    }
+
+    /// Gets the dates of all snapshots, oldest first.
+    pub fn dates(&self) -> impl Iterator<Item = Date> + '_ {
+        self.snapshots.keys().copied()
+    }
+
+    /// Parses an ECB-style reference rate CSV file.
+    ///
+    /// The header is `Date,<code>,<code>,...`, followed by one row per date with the number of
+    /// units of each currency per euro. Unknown codes and `N/A` values are skipped.
+    pub fn from_ecb_csv(csv: &str) -> Result<Self, RateError> {
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        let header = match lines.next() {
+            Some((_, header)) => header,
+            None => return Ok(Self::new()),
+        };
+        let codes: Vec<Option<Currency>> = header
+            .split(',')
+            .skip(1)
+            .map(|c| parse_code(c.trim()))
+            .collect();
+
+        let mut provider = Self::new();
+        for (i, line) in lines {
+            let err = RateError::Parse { line: i + 1 };
+            let mut fields = line.split(',').map(str::trim);
+            let date: Date = fields.next().and_then(|d| d.parse().ok()).ok_or(err)?;
+
+            let mut rates = ExchangeRates::new();
+            for (code, value) in codes.iter().zip(fields) {
+                match (code, value) {
+                    (Some(code), value) if !value.is_empty() && value != "N/A" => {
+                        rates.insert(Currency::EUR, *code, value.parse().map_err(|_| err)?);
+                    }
+                    _ => (),
+                }
+            }
+            provider.insert(date, rates);
+        }
+
+        Ok(provider)
+    }
+
+    /// Parses an ECB-style reference rate XML file, e.g. `eurofxref-daily.xml`.
+    ///
+    /// Each `<Cube time="...">` element starts a snapshot, and each nested
+    /// `<Cube currency="..." rate="..."/>` element holds the number of units per euro.
+    pub fn from_ecb_xml(xml: &str) -> Result<Self, RateError> {
+        let mut provider = Self::new();
+        let mut current: Option<(Date, ExchangeRates)> = None;
+
+        for (i, line) in xml.lines().enumerate() {
+            for tag in line.split('<').skip(1) {
+                let tag = match tag.strip_prefix("Cube") {
+                    Some(tag) => tag,
+                    None => continue,
+                };
+                let err = RateError::Parse { line: i + 1 };
+
+                if let Some(time) = xml_attr(tag, "time") {
+                    if let Some((date, rates)) = current.take() {
+                        provider.insert(date, rates);
+                    }
+                    let date = time.parse().map_err(|_| err)?;
+                    current = Some((date, ExchangeRates::new()));
+                } else if let (Some(code), Some(rate)) =
+                    (xml_attr(tag, "currency"), xml_attr(tag, "rate"))
+                {
+                    let (_, rates) = current.as_mut().ok_or(err)?;
+                    if let Some(code) = parse_code(code) {
+                        rates.insert(Currency::EUR, code, rate.parse().map_err(|_| err)?);
+                    }
+                }
+            }
+        }
+
+        if let Some((date, rates)) = current {
+            provider.insert(date, rates);
+        }
+
+        Ok(provider)
+    }
+}
+
+impl ExchangeRateProvider for InMemoryProvider {
+    fn rate(&self, from: Currency, to: Currency, at: Date) -> Result<Rate, RateError> {
+        match self.snapshot(at) {
+            Some((_, rates)) => rates.rate(from, to),
+            None => Err(RateError::NotFound { from, to }),
+        }
+    }
+}
+
+/// Gets the value of an attribute in an XML start tag, with either quote style.
+fn xml_attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(pos) = rest.find(name) {
+        let after = &rest[pos + name.len()..];
+        let boundary = pos == 0 || rest.as_bytes()[pos - 1].is_ascii_whitespace();
+        if let Some(value) = after.trim_start().strip_prefix('=') {
+            let value = value.trim_start();
+            if let (true, Some(quote)) = (
+                boundary,
+                value.chars().next().filter(|c| *c == '"' || *c == '\''),
+            ) {
+                let value = &value[1..];
+                return value.find(quote).map(|end| &value[..end]);
+            }
+        }
+        rest = after;
+    }
+    None
+}
+
+/// Tries a list of providers in order, returning the first rate found.
+#[derive(Default)]
+pub struct ChainedProvider<'a> {
+    providers: Vec<Box<dyn ExchangeRateProvider + 'a>>,
+}
+
+impl<'a> ChainedProvider<'a> {
+    /// Creates a new, empty [ChainedProvider].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a fallback provider, tried after the ones already in the chain.
+    pub fn with<P: ExchangeRateProvider + 'a>(mut self, provider: P) -> Self {
+        self.push(provider);
+        self
+    }
+
+    /// Adds a fallback provider, tried after the ones already in the chain.
+    pub fn push<P: ExchangeRateProvider + 'a>(&mut self, provider: P) {
+        self.providers.push(Box::new(provider));
+    }
+
+    /// Gets the number of providers in the chain.
+    pub fn len(&self) -> usize {
+        self.providers.len()
+    }
+
+    /// Gets whether the chain is empty.
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+}
+
+impl ExchangeRateProvider for ChainedProvider<'_> {
+    /// Gets the first rate found, or the error from the last provider in the chain.
+    fn rate(&self, from: Currency, to: Currency, at: Date) -> Result<Rate, RateError> {
+        let mut res = Err(RateError::NotFound { from, to });
+        for provider in self.providers.iter() {
+            res = provider.rate(from, to, at);
+            if res.is_ok() {
+                break;
+            }
+        }
+        res
+    }
+}
+
+#[cfg(feature = "std")]
+pub use self::file::{CachedProvider, FileProvider, SnapshotFormat};
+
+#[cfg(feature = "std")]
+mod file {
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+    use std::{fs, io};
+
+    use super::{ExchangeRateProvider, InMemoryProvider};
+    use crate::{Currency, Date, Rate, RateError};
+
+    /// File formats understood by [FileProvider].
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum SnapshotFormat {
+        /// ECB-style CSV, see [InMemoryProvider::from_ecb_csv].
+        EcbCsv,
+        /// ECB-style XML, see [InMemoryProvider::from_ecb_xml].
+        EcbXml,
+    }
+
+    /// Exchange rates loaded from an ECB-style snapshot file.
+    #[derive(Clone, Debug)]
+    pub struct FileProvider {
+        path: PathBuf,
+        format: SnapshotFormat,
+        rates: InMemoryProvider,
+    }
+
+    impl FileProvider {
+        /// Loads a snapshot file, using the extension (`.xml` or `.csv`) to pick the format.
+        pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+            let path = path.as_ref();
+            let format = match path.extension().and_then(|e| e.to_str()) {
+                Some(ext) if ext.eq_ignore_ascii_case("xml") => SnapshotFormat::EcbXml,
+                Some(ext) if ext.eq_ignore_ascii_case("csv") => SnapshotFormat::EcbCsv,
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "unknown snapshot file extension",
+                    ))
+                }
+            };
+            Self::open_with_format(path, format)
+        }
+
+        /// Loads a snapshot file in the given format.
+        pub fn open_with_format<P: AsRef<Path>>(
+            path: P,
+            format: SnapshotFormat,
+        ) -> io::Result<Self> {
+            let mut provider = Self {
+                path: path.as_ref().to_path_buf(),
+                format,
+                rates: InMemoryProvider::new(),
+            };
+            provider.reload()?;
+            Ok(provider)
+        }
+
+        /// Re-reads the snapshot file, e.g. after it was replaced by a newer download.
+        pub fn reload(&mut self) -> io::Result<()> {
+            let contents = fs::read_to_string(&self.path)?;
+            let rates = match self.format {
+                SnapshotFormat::EcbCsv => InMemoryProvider::from_ecb_csv(&contents),
+                SnapshotFormat::EcbXml => InMemoryProvider::from_ecb_xml(&contents),
+            };
+            self.rates = rates.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            Ok(())
+        }
+
+        /// Gets the path of the snapshot file.
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+
+        /// Gets the loaded rates.
+        pub fn rates(&self) -> &InMemoryProvider {
+            &self.rates
+        }
+    }
+
+    impl ExchangeRateProvider for FileProvider {
+        fn rate(&self, from: Currency, to: Currency, at: Date) -> Result<Rate, RateError> {
+            self.rates.rate(from, to, at)
+        }
+    }
+
+    type CacheKey = (Currency, Currency, Date);
+
+    /// Caches rates from another provider for a fixed time-to-live.
+    ///
+    /// Only successful lookups are cached. Expired rates are evicted whenever a new rate is
+    /// cached, so the cache only grows with the number of distinct lookups within the TTL.
+    #[derive(Debug)]
+    pub struct CachedProvider<P> {
+        inner: P,
+        ttl: Duration,
+        cache: Mutex<HashMap<CacheKey, (Rate, Instant)>>,
+    }
+
+    impl<P: ExchangeRateProvider> CachedProvider<P> {
+        /// Creates a new [CachedProvider] wrapping `inner`.
+        pub fn new(inner: P, ttl: Duration) -> Self {
+            Self {
+                inner,
+                ttl,
+                cache: Mutex::new(HashMap::new()),
+            }
+        }
+
+        /// Gets the wrapped provider.
+        pub fn inner(&self) -> &P {
+            &self.inner
+        }
+
+        /// Gets the time-to-live of cached rates.
+        pub fn ttl(&self) -> Duration {
+            self.ttl
+        }
+
+        /// Drops all cached rates.
+        pub fn clear(&self) {
+            self.lock().clear();
+        }
+
+        /// Gets the number of cached rates, including expired ones not yet evicted.
+        pub fn len(&self) -> usize {
+            self.lock().len()
+        }
+
+        /// Gets whether no rates are cached.
+        pub fn is_empty(&self) -> bool {
+            self.lock().is_empty()
+        }
+
+        fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, (Rate, Instant)>> {
+            // a poisoned cache only holds complete entries, so keep using it
+            self.cache.lock().unwrap_or_else(|e| e.into_inner())
+        }
+    }
+
+    impl<P: ExchangeRateProvider> ExchangeRateProvider for CachedProvider<P> {
+        fn rate(&self, from: Currency, to: Currency, at: Date) -> Result<Rate, RateError> {
+            let key = (from, to, at);
+            let now = Instant::now();
+
+            if let Some(&(rate, stored)) = self.lock().get(&key) {
+                if now.duration_since(stored) < self.ttl {
+                    return Ok(rate);
+                }
+            }
+
+            let rate = self.inner.rate(from, to, at)?;
+            let mut cache = self.lock();
+            cache.retain(|_, &mut (_, stored)| now.duration_since(stored) < self.ttl);
+            cache.insert(key, (rate, now));
+            Ok(rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ECB_CSV: &str = include_str!("../tests/fixtures/eurofxref-hist.csv");
+    const ECB_XML: &str = include_str!("../tests/fixtures/eurofxref-daily.xml");
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_ecb_csv() {
+        let rates = InMemoryProvider::from_ecb_csv(ECB_CSV).unwrap();
+
+        assert_eq!(rates.dates().count(), 3);
+        assert_eq!(
+            rates.rate(Currency::EUR, Currency::USD, date("2024-01-15")),
+            Ok("1.0945".parse().unwrap())
+        );
+        // weekend uses the previous business day
+        assert_eq!(
+            rates.rate(Currency::EUR, Currency::USD, date("2024-01-14")),
+            Ok("1.0950".parse().unwrap())
+        );
+        // cross rate via EUR
+        assert_eq!(
+            rates.rate(Currency::USD, Currency::JPY, date("2024-01-16")),
+            Ok(Rate::new(1_609_500, 10_882).unwrap())
+        );
+        // N/A value
+        assert!(rates
+            .rate(Currency::EUR, Currency::RUB, date("2024-01-16"))
+            .is_err());
+        // before the first snapshot
+        assert_eq!(
+            rates.rate(Currency::EUR, Currency::USD, date("2024-01-01")),
+            Err(RateError::NotFound {
+                from: Currency::EUR,
+                to: Currency::USD
+            })
+        );
+    }
+
+    #[test]
+    fn test_ecb_xml() {
+        let rates = InMemoryProvider::from_ecb_xml(ECB_XML).unwrap();
+
+        assert_eq!(rates.dates().count(), 2);
+        assert_eq!(
+            rates.rate(Currency::EUR, Currency::GBP, date("2024-01-16")),
+            Ok("0.85808".parse().unwrap())
+        );
+        assert_eq!(
+            rates.rate(Currency::GBP, Currency::EUR, date("2024-01-15")),
+            Ok("0.86053".parse::<Rate>().unwrap().inverse())
+        );
+    }
+
+    #[test]
+    fn test_chained() {
+        let ecb = InMemoryProvider::from_ecb_csv(ECB_CSV).unwrap();
+        let mut fallback = ExchangeRates::new();
+        fallback.insert(Currency::EUR, Currency::NOK, "11.4".parse().unwrap());
+
+        let chain = ChainedProvider::new().with(&ecb).with(fallback);
+        let at = date("2024-01-16");
+
+        assert_eq!(chain.len(), 2);
+        assert_eq!(
+            chain.rate(Currency::EUR, Currency::USD, at),
+            Ok("1.0882".parse().unwrap())
+        );
+        assert_eq!(
+            chain.rate(Currency::EUR, Currency::NOK, at),
+            Ok("11.4".parse().unwrap())
+        );
+        assert!(chain.rate(Currency::EUR, Currency::SEK, at).is_err());
+        assert!(ChainedProvider::new()
+            .rate(Currency::EUR, Currency::USD, at)
+            .is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_file_provider() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let csv = FileProvider::open(dir.join("eurofxref-hist.csv")).unwrap();
+        let xml = FileProvider::open(dir.join("eurofxref-daily.xml")).unwrap();
+        let at = date("2024-01-16");
+
+        assert_eq!(
+            csv.rate(Currency::EUR, Currency::USD, at),
+            xml.rate(Currency::EUR, Currency::USD, at)
+        );
+        assert!(FileProvider::open(dir.join("rates.txt")).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cached() {
+        use std::cell::Cell;
+        use std::time::Duration;
+
+        struct Counting(Cell<usize>);
+
+        impl ExchangeRateProvider for Counting {
+            fn rate(&self, _: Currency, _: Currency, _: Date) -> Result<Rate, RateError> {
+                self.0.set(self.0.get() + 1);
+                Ok(Rate::ONE)
+            }
+        }
+
+        let at = date("2024-01-16");
+        let cached = CachedProvider::new(Counting(Cell::new(0)), Duration::from_secs(3600));
+        for _ in 0..3 {
+            assert_eq!(cached.rate(Currency::EUR, Currency::USD, at), Ok(Rate::ONE));
+        }
+        assert_eq!(cached.inner().0.get(), 1);
+
+        cached.clear();
+        assert!(cached.rate(Currency::EUR, Currency::USD, at).is_ok());
+        assert_eq!(cached.inner().0.get(), 2);
+
+        let expired = CachedProvider::new(Counting(Cell::new(0)), Duration::ZERO);
+        for _ in 0..3 {
+            assert!(expired.rate(Currency::EUR, Currency::USD, at).is_ok());
+        }
+        assert_eq!(expired.inner().0.get(), 3);
+        assert_eq!(expired.len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cached_eviction() {
+        use std::time::Duration;
+
+        let rates = InMemoryProvider::from_ecb_csv(ECB_CSV).unwrap();
+        let dates = ["2024-01-12", "2024-01-15", "2024-01-16"].map(date);
+
+        let cached = CachedProvider::new(rates.clone(), Duration::from_secs(3600));
+        for at in dates {
+            assert!(cached.rate(Currency::EUR, Currency::USD, at).is_ok());
+            assert!(cached.rate(Currency::EUR, Currency::JPY, at).is_ok());
+        }
+        assert_eq!(cached.len(), 6);
+
+        // every new lookup evicts the expired ones
+        let expired = CachedProvider::new(rates, Duration::ZERO);
+        for at in dates {
+            assert!(expired.rate(Currency::EUR, Currency::USD, at).is_ok());
+            assert!(expired.rate(Currency::EUR, Currency::JPY, at).is_ok());
+            assert_eq!(expired.len(), 1);
+        }
+        assert!(!expired.is_empty());
+        expired.clear();
+        assert!(expired.is_empty());
+    }
+}
@@ -0,0 +1,612 @@
+//! Exchange rate tables and currency conversion.
+//!
+//! Rates are exact rationals, so conversions only round once, at the end, with an explicit
+//! [Rounding] strategy.
+//!
+//! ```
+//! use currency_iso4217::{Currency, ExchangeRates, Money, Rounding};
+//!
+//! let rates = ExchangeRates::from_csv("from,to,rate\nEUR,USD,1.0850\nUSD,JPY,151.20\n").unwrap();
+//!
+//! let eur = Money::from_minor(Currency::EUR, 10000);
+//! let jpy = rates.convert(eur, Currency::JPY, Rounding::HalfEven).unwrap();
+//!
+//! assert_eq!(jpy, Money::from_minor(Currency::JPY, 16405));
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::money::pow10;
+use crate::std::{fmt, str::FromStr};
+use crate::{Currency, CurrencyUnit, Money, Rounding};
+
+/// Default pivot currencies used for cross-rate triangulation.
+pub const DEFAULT_PIVOTS: [Currency; 2] = [Currency::USD, Currency::EUR];
+
+/// Errors returned by exchange rate lookups and conversions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RateError {
+    /// No rate is available for the currency pair, directly or via a pivot currency.
+    NotFound {
+        /// Source currency.
+        from: Currency,
+        /// Target currency.
+        to: Currency,
+    },
+    /// The rate is zero, or not a valid decimal number.
+    InvalidRate,
+    /// Arithmetic overflow while deriving a rate or converting an amount.
+    Overflow,
+    /// Malformed rate table input, with the 1-based line number.
+    Parse {
+        /// Line number of the malformed record.
+        line: usize,
+    },
+}
+
+impl fmt::Display for RateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { from, to } => {
+                write!(f, "no exchange rate for {}/{}", from.alpha(), to.alpha())
+            }
+            Self::InvalidRate => write!(f, "invalid exchange rate"),
+            Self::Overflow => write!(f, "arithmetic overflow"),
+            Self::Parse { line } => write!(f, "malformed exchange rate on line {line}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RateError {}
+
+/// An exact, positive exchange rate expressed as a reduced fraction.
+///
+/// A rate for `from -> to` is the number of major units of `to` bought by one major unit of
+/// `from`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rate {
+    num: u128,
+    den: u128,
+}
+
+impl Rate {
+    /// The identity rate, `1/1`.
+    pub const ONE: Self = Self { num: 1, den: 1 };
+
+    /// Creates a new [Rate] from a fraction `num / den`.
+    ///
+    /// Returns `None` if either part is zero.
+    pub const fn new(num: u128, den: u128) -> Option<Self> {
+        if num == 0 || den == 0 {
+            None
+        } else {
+            let g = gcd(num, den);
+            Some(Self {
+                num: num / g,
+                den: den / g,
+            })
+        }
+    }
+
+    /// Gets the numerator of the reduced fraction.
+    pub const fn numerator(&self) -> u128 {
+        self.num
+    }
+
+    /// Gets the denominator of the reduced fraction.
+    pub const fn denominator(&self) -> u128 {
+        self.den
+    }
+
+    /// Gets the inverse rate, e.g. `USD -> EUR` from `EUR -> USD`.
+    pub const fn inverse(&self) -> Self {
+        Self {
+            num: self.den,
+            den: self.num,
+        }
+    }
+
+    /// Multiplies two rates, e.g. `EUR -> USD` and `USD -> JPY` into `EUR -> JPY`.
+    ///
+    /// Returns `None` on overflow.
+    pub const fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        // cross-reduce first to keep the intermediate values small
+        let g1 = gcd(self.num, rhs.den);
+        let g2 = gcd(rhs.num, self.den);
+        let num = (self.num / g1).checked_mul(rhs.num / g2);
+        let den = (self.den / g2).checked_mul(rhs.den / g1);
+
+        match (num, den) {
+            (Some(num), Some(den)) => Some(Self { num, den }),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Rate {
+    type Err = RateError;
+
+    /// Parses a positive decimal number, e.g. `1.0856`, or a fraction, e.g. `217/200`, into an
+    /// exact rate.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((num, den)) = s.split_once('/') {
+            if den.contains('/') {
+                return Err(RateError::InvalidRate);
+            }
+            let num: Self = num.parse()?;
+            let den: Self = den.parse()?;
+            return num.checked_mul(&den.inverse()).ok_or(RateError::Overflow);
+        }
+
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+
+        if (int.is_empty() && frac.is_empty())
+            || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return Err(RateError::InvalidRate);
+        }
+
+        let mut num = 0u128;
+        let mut den = 1u128;
+        for b in int.bytes() {
+            num = num
+                .checked_mul(10)
+                .and_then(|n| n.checked_add((b - b'0') as u128))
+                .ok_or(RateError::Overflow)?;
+        }
+        for b in frac.bytes() {
+            num = num
+                .checked_mul(10)
+                .and_then(|n| n.checked_add((b - b'0') as u128))
+                .ok_or(RateError::Overflow)?;
+            den = den.checked_mul(10).ok_or(RateError::Overflow)?;
+        }
+
+        Self::new(num, den).ok_or(RateError::InvalidRate)
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+/// Serializes as a fraction string, e.g. `"217/200"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from a decimal or fraction string, e.g. `"1.0850"` or `"217/200"`, or a number.
+///
+/// Floating-point numbers are read from their shortest decimal representation, e.g. `1.085`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Rate;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a positive exchange rate")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Rate, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Rate, E> {
+                Rate::new(v.into(), 1).ok_or_else(|| E::custom(RateError::InvalidRate))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Rate, E> {
+                u64::try_from(v)
+                    .map_err(|_| E::custom(RateError::InvalidRate))
+                    .and_then(|v| self.visit_u64(v))
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Rate, E> {
+                if !v.is_finite() {
+                    return Err(E::custom(RateError::InvalidRate));
+                }
+                self.visit_str(&alloc::format!("{v}"))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// A table of exchange rates keyed by currency pair.
+///
+/// Lookups fall back to the inverse of the opposite pair, and then to triangulation via the
+/// configured pivot currencies (by default [DEFAULT_PIVOTS]).
+///
+/// Tables are loaded from CSV with [from_csv](Self::from_csv), or with the `serde` feature from
+/// a list of `{"from", "to", "rate"}` records, e.g. in JSON.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExchangeRates {
+    rates: BTreeMap<(Currency, Currency), Rate>,
+    pivots: Vec<Currency>,
+}
+
+impl ExchangeRates {
+    /// Creates a new, empty [ExchangeRates] table.
+    pub fn new() -> Self {
+        Self {
+            rates: BTreeMap::new(),
+            pivots: DEFAULT_PIVOTS.to_vec(),
+        }
+    }
+
+    /// Sets the pivot currencies used for triangulation, in order of preference.
+    pub fn with_pivots(mut self, pivots: &[Currency]) -> Self {
+        self.pivots = pivots.to_vec();
+        self
+    }
+
+    /// Gets the pivot currencies used for triangulation.
+    pub fn pivots(&self) -> &[Currency] {
+        &self.pivots
+    }
+
+    /// Inserts a rate for `from -> to`, returning the previous rate for the pair, if any.
+    pub fn insert(&mut self, from: Currency, to: Currency, rate: Rate) -> Option<Rate> {
+        self.rates.insert((from, to), rate)
+    }
+
+    /// Gets the number of rates stored in the table.
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// Gets whether the table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// Gets an iterator over the stored `(from, to, rate)` entries.
+    pub fn iter(&self) -> impl Iterator<Item = (Currency, Currency, Rate)> + '_ {
+        self.rates
+            .iter()
+            .map(|(&(from, to), &rate)| (from, to, rate))
+    }
+
+    /// Gets the stored rate for `from -> to`, or the inverse of a stored `to -> from` rate.
+    pub fn direct(&self, from: Currency, to: Currency) -> Option<Rate> {
+        if from == to {
+            Some(Rate::ONE)
+        } else if let Some(&rate) = self.rates.get(&(from, to)) {
+            Some(rate)
+        } else {
+            self.rates.get(&(to, from)).map(Rate::inverse)
+        }
+    }
+
+    /// Gets the rate for `from -> to`, triangulating via a pivot currency if needed.
+    pub fn rate(&self, from: Currency, to: Currency) -> Result<Rate, RateError> {
+        if let Some(rate) = self.direct(from, to) {
+            return Ok(rate);
+        }
+
+        let mut overflow = false;
+        for &pivot in self.pivots.iter() {
+            if let (Some(a), Some(b)) = (self.direct(from, pivot), self.direct(pivot, to)) {
+                match a.checked_mul(&b) {
+                    Some(rate) => return Ok(rate),
+                    None => overflow = true,
+                }
+            }
+        }
+
+        if overflow {
+            Err(RateError::Overflow)
+        } else {
+            Err(RateError::NotFound { from, to })
+        }
+    }
+
+    /// Converts `money` into the `to` currency, rounding to its minor units.
+    pub fn convert(
+        &self,
+        money: Money,
+        to: Currency,
+        rounding: Rounding,
+    ) -> Result<Money, RateError> {
+        let rate = self.rate(money.currency(), to)?;
+        convert(money, to, &rate, rounding)
+    }
+
+    /// Parses a rate table from CSV records of `from,to,rate`.
+    ///
+    /// Blank lines, lines starting with `#`, and an optional `from,to,rate` header are skipped.
+    pub fn from_csv(csv: &str) -> Result<Self, RateError> {
+        let mut rates = Self::new();
+
+        for (i, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || (i == 0 && is_header(line)) {
+                continue;
+            }
+
+            let err = RateError::Parse { line: i + 1 };
+            let mut fields = line.split(',').map(str::trim);
+            let (from, to, rate) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(from), Some(to), Some(rate)) if fields.next().is_none() => (from, to, rate),
+                _ => return Err(err),
+            };
+            let from = parse_code(from).ok_or(err)?;
+            let to = parse_code(to).ok_or(err)?;
+
+            rates.insert(from, to, rate.parse().map_err(|_| err)?);
+        }
+
+        Ok(rates)
+    }
+}
+
+impl Default for ExchangeRates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `from,to,rate` record, as in [ExchangeRates::from_csv].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize, serde::Serialize)]
+struct Record {
+    from: Currency,
+    to: Currency,
+    rate: Rate,
+}
+
+/// Serializes the stored rates as a list of `{"from", "to", "rate"}` records, without the
+/// pivot currencies.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExchangeRates {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.iter()
+                .map(|(from, to, rate)| Record { from, to, rate }),
+        )
+    }
+}
+
+/// Deserializes a list of `{"from", "to", "rate"}` records, e.g. from JSON, with the
+/// [DEFAULT_PIVOTS].
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExchangeRates {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut rates = Self::new();
+        for record in Vec::<Record>::deserialize(deserializer)? {
+            rates.insert(record.from, record.to, record.rate);
+        }
+        Ok(rates)
+    }
+}
+
+/// Converts `money` into the `to` currency at `rate`, rounding to the minor units of `to`.
+///
+/// Works with any [CurrencyUnit], e.g. to convert an [AnyMoney](crate::AnyMoney) in a custom
+/// currency.
+pub fn convert<C: CurrencyUnit, D: CurrencyUnit>(
+    money: Money<C>,
+    to: D,
+    rate: &Rate,
+    rounding: Rounding,
+) -> Result<Money<D>, RateError> {
+    let from_exp = money.exponent();
+    let to_exp = to.exponent();
+
+    let num = i128::try_from(rate.num).map_err(|_| RateError::Overflow)?;
+    let den = i128::try_from(rate.den).map_err(|_| RateError::Overflow)?;
+
+    // scale between the minor units of both currencies
+    let (num, den) = if to_exp >= from_exp {
+        (num.checked_mul(pow10(to_exp - from_exp)), Some(den))
+    } else {
+        (Some(num), den.checked_mul(pow10(from_exp - to_exp)))
+    };
+
+    let amount = num
+        .and_then(|n| money.amount().checked_mul(n))
+        .zip(den)
+        .and_then(|(n, d)| rounding.div(n, d))
+        .ok_or(RateError::Overflow)?;
+
+    Ok(Money::from_minor(to, amount))
+}
+
+fn is_header(line: &str) -> bool {
+    line.split(',')
+        .next()
+        .map(|f| f.trim().eq_ignore_ascii_case("from"))
+        .unwrap_or(false)
+}
+
+/// Parses an alphabetic code, rejecting anything other than a known three-letter code.
+pub(crate) fn parse_code(code: &str) -> Option<Currency> {
+    let currency = Currency::from(code);
+    if code.len() == Currency::LEN
+        && (currency != Currency::XXX || code.eq_ignore_ascii_case("XXX"))
+    {
+        Some(currency)
+    } else {
+        None
+    }
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/rates.csv");
+    #[cfg(feature = "serde")]
+    const JSON_FIXTURE: &str = include_str!("../tests/fixtures/rates.json");
+
+    #[test]
+    fn test_rate_parse() {
+        assert_eq!("1.0850".parse::<Rate>(), Ok(Rate::new(217, 200).unwrap()));
+        assert_eq!("151".parse::<Rate>(), Ok(Rate::new(151, 1).unwrap()));
+        assert_eq!(".5".parse::<Rate>(), Ok(Rate::new(1, 2).unwrap()));
+        assert_eq!("0".parse::<Rate>(), Err(RateError::InvalidRate));
+        assert_eq!("-1.2".parse::<Rate>(), Err(RateError::InvalidRate));
+        assert_eq!("1,2".parse::<Rate>(), Err(RateError::InvalidRate));
+        assert_eq!(".".parse::<Rate>(), Err(RateError::InvalidRate));
+        assert_eq!("217/200".parse::<Rate>(), Ok(Rate::new(217, 200).unwrap()));
+        assert_eq!("1.5/0.5".parse::<Rate>(), Ok(Rate::new(3, 1).unwrap()));
+        assert_eq!("1/0".parse::<Rate>(), Err(RateError::InvalidRate));
+        assert_eq!("1/2/3".parse::<Rate>(), Err(RateError::InvalidRate));
+    }
+
+    #[test]
+    fn test_fixture() {
+        let rates = ExchangeRates::from_csv(FIXTURE).unwrap();
+
+        assert_eq!(rates.len(), 4);
+        assert_eq!(
+            rates.direct(Currency::EUR, Currency::USD),
+            Some(Rate::new(217, 200).unwrap())
+        );
+        // inverse of the stored pair
+        assert_eq!(
+            rates.direct(Currency::USD, Currency::EUR),
+            Some(Rate::new(200, 217).unwrap())
+        );
+        assert_eq!(rates.direct(Currency::CHF, Currency::CHF), Some(Rate::ONE));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_json_fixture() {
+        let rates: ExchangeRates = serde_json::from_str(JSON_FIXTURE).unwrap();
+        assert_eq!(rates, ExchangeRates::from_csv(FIXTURE).unwrap());
+        assert_eq!(
+            rates.convert(
+                Money::from_minor(Currency::EUR, 10000),
+                Currency::JPY,
+                Rounding::HalfEven
+            ),
+            Ok(Money::from_minor(Currency::JPY, 16405))
+        );
+
+        let json = serde_json::to_string(&rates).unwrap();
+        assert!(json.contains(r#"{"from":"EUR","to":"USD","rate":"217/200"}"#));
+        assert_eq!(serde_json::from_str::<ExchangeRates>(&json).unwrap(), rates);
+
+        assert!(
+            serde_json::from_str::<ExchangeRates>(r#"[{"from":"EUR","to":"USD","rate":"0"}]"#)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<ExchangeRates>(
+            r#"[{"from":"EUR","to":"QQQ","rate":"1.1"}]"#
+        )
+        .is_err());
+        assert!(
+            serde_json::from_str::<ExchangeRates>(r#"[{"from":"EUR","to":"USD","rate":-1}]"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_triangulation() {
+        let rates = ExchangeRates::from_csv(FIXTURE).unwrap();
+
+        // EUR -> USD -> JPY
+        assert_eq!(
+            rates.rate(Currency::EUR, Currency::JPY),
+            Ok(Rate::new(217 * 15120, 200 * 100).unwrap())
+        );
+        // GBP -> EUR -> USD, only the EUR pivot has both legs
+        assert_eq!(
+            rates.rate(Currency::GBP, Currency::USD),
+            Ok(Rate::new(233 * 217, 200 * 200).unwrap())
+        );
+        assert_eq!(
+            rates.rate(Currency::CHF, Currency::JPY),
+            Err(RateError::NotFound {
+                from: Currency::CHF,
+                to: Currency::JPY
+            })
+        );
+        assert_eq!(
+            rates
+                .rate(Currency::CHF, Currency::JPY)
+                .unwrap_err()
+                .to_string(),
+            "no exchange rate for CHF/JPY"
+        );
+
+        let no_pivots = ExchangeRates::from_csv(FIXTURE).unwrap().with_pivots(&[]);
+        assert!(no_pivots.rate(Currency::EUR, Currency::JPY).is_err());
+    }
+
+    #[test]
+    fn test_convert() {
+        let rates = ExchangeRates::from_csv(FIXTURE).unwrap();
+
+        let eur = Money::from_minor(Currency::EUR, 10000);
+        assert_eq!(
+            rates.convert(eur, Currency::USD, Rounding::HalfEven),
+            Ok(Money::from_minor(Currency::USD, 10850))
+        );
+        // 100 EUR = 108.50 USD = 16405.20 JPY
+        assert_eq!(
+            rates.convert(eur, Currency::JPY, Rounding::HalfEven),
+            Ok(Money::from_minor(Currency::JPY, 16405))
+        );
+        assert_eq!(
+            rates.convert(eur, Currency::JPY, Rounding::Ceiling),
+            Ok(Money::from_minor(Currency::JPY, 16406))
+        );
+        // 0.3075 KWD per USD, three decimal places
+        // 10.07 USD = 3.096525 KWD
+        let usd = Money::from_minor(Currency::USD, 1007);
+        assert_eq!(
+            rates.convert(usd, Currency::KWD, Rounding::HalfUp),
+            Ok(Money::from_minor(Currency::KWD, 3_097))
+        );
+        assert_eq!(
+            rates.convert(usd, Currency::KWD, Rounding::Down),
+            Ok(Money::from_minor(Currency::KWD, 3_096))
+        );
+        let back = Money::from_minor(Currency::KWD, 3_075);
+        assert_eq!(
+            rates.convert(back, Currency::USD, Rounding::HalfEven),
+            Ok(Money::from_minor(Currency::USD, 1000))
+        );
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        assert_eq!(
+            ExchangeRates::from_csv("EUR,USD,1.08\nEUR,ZZZ,1.0\n"),
+            Err(RateError::Parse { line: 2 })
+        );
+        assert_eq!(
+            ExchangeRates::from_csv("EUR,USD"),
+            Err(RateError::Parse { line: 1 })
+        );
+        assert_eq!(
+            ExchangeRates::from_csv("EUR,USD,0"),
+            Err(RateError::Parse { line: 1 })
+        );
+    }
+}
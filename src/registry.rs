@@ -0,0 +1,440 @@
+//! Non-ISO currencies (crypto assets, loyalty points, internal units) registered at runtime.
+//!
+//! ```
+//! use currency_iso4217::registry::{AnyMoney, CurrencyRegistry, CustomCurrency};
+//! use currency_iso4217::{Currency, Decimal, Rounding};
+//!
+//! let mut registry = CurrencyRegistry::new();
+//! registry
+//!     .register(CustomCurrency::new("BTC", "Bitcoin", 8).with_symbol("₿"))
+//!     .unwrap();
+//!
+//! let btc = registry.get("BTC").unwrap();
+//! let amount = AnyMoney::from_decimal(btc, "0.123456789".parse().unwrap(), Rounding::HalfEven);
+//! assert_eq!(amount.unwrap().to_string(), "0.12345679 BTC");
+//!
+//! assert_eq!(registry.get("USD"), Some(Currency::USD.into()));
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::sync::Arc;
+
+use crate::std::fmt;
+use crate::{Currency, CurrencyUnit, Decimal, Money};
+
+/// Maximum length of a custom currency code.
+pub const MAX_CODE_LEN: usize = 12;
+
+/// A currency outside ISO 4217, e.g. `BTC` or loyalty points.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CustomCurrency {
+    code: String,
+    name: String,
+    minor_units: u8,
+    symbol: Option<String>,
+}
+
+impl CustomCurrency {
+    /// Creates a new [CustomCurrency].
+    pub fn new(code: &str, name: &str, minor_units: u8) -> Self {
+        Self {
+            code: code.into(),
+            name: name.into(),
+            minor_units,
+            symbol: None,
+        }
+    }
+
+    /// Sets the symbol, e.g. `₿`.
+    pub fn with_symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// Gets the code, e.g. `BTC`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Gets the name, e.g. `Bitcoin`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the number of minor units (digits after the decimal separator).
+    pub const fn minor_units(&self) -> u8 {
+        self.minor_units
+    }
+
+    /// Gets the symbol, if any.
+    pub fn symbol(&self) -> Option<&str> {
+        self.symbol.as_deref()
+    }
+}
+
+/// Either an ISO 4217 [Currency] or a registered [CustomCurrency].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnyCurrency {
+    /// An ISO 4217 currency.
+    Iso(Currency),
+    /// A custom currency from a [CurrencyRegistry].
+    Custom(Arc<CustomCurrency>),
+}
+
+impl AnyCurrency {
+    /// Gets the code, e.g. `USD` or `BTC`.
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Iso(c) => c.alpha(),
+            Self::Custom(c) => c.code(),
+        }
+    }
+
+    /// Gets the name of the currency (in English).
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Iso(c) => c.name(),
+            Self::Custom(c) => c.name(),
+        }
+    }
+
+    /// Gets the number of minor units, see [Currency::minor_units].
+    pub fn minor_units(&self) -> Option<u8> {
+        match self {
+            Self::Iso(c) => c.minor_units(),
+            Self::Custom(c) => Some(c.minor_units()),
+        }
+    }
+
+    /// Gets the decimal exponent used for amounts, see [Currency::exponent].
+    pub fn exponent(&self) -> u8 {
+        self.minor_units().unwrap_or(0)
+    }
+
+    /// Gets the local symbol, if any, see [Currency::symbol].
+    pub fn symbol(&self) -> Option<&str> {
+        match self {
+            Self::Iso(c) => c.symbol(),
+            Self::Custom(c) => c.symbol(),
+        }
+    }
+
+    /// Gets the ISO 4217 [Currency], `None` for custom currencies.
+    pub fn iso(&self) -> Option<Currency> {
+        match self {
+            Self::Iso(c) => Some(*c),
+            Self::Custom(_) => None,
+        }
+    }
+}
+
+impl Default for AnyCurrency {
+    fn default() -> Self {
+        Self::Iso(Currency::default())
+    }
+}
+
+impl From<Currency> for AnyCurrency {
+    fn from(val: Currency) -> Self {
+        Self::Iso(val)
+    }
+}
+
+impl fmt::Display for AnyCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Errors returned when registering a [CustomCurrency].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RegistryError {
+    /// The code is not 2 to [MAX_CODE_LEN] ASCII upper-case letters or digits.
+    InvalidCode,
+    /// The minor units exceed [Decimal::MAX_SCALE].
+    InvalidMinorUnits,
+    /// The code is an ISO 4217 code.
+    IsoCode(Currency),
+    /// The code is already registered.
+    Duplicate(String),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCode => write!(
+                f,
+                "currency code must be 2 to {MAX_CODE_LEN} upper-case letters or digits"
+            ),
+            Self::InvalidMinorUnits => {
+                write!(f, "minor units must be at most {}", Decimal::MAX_SCALE)
+            }
+            Self::IsoCode(c) => write!(f, "{} is an ISO 4217 currency code", c.alpha()),
+            Self::Duplicate(code) => write!(f, "{code} is already registered"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegistryError {}
+
+/// A registry of [CustomCurrency] codes, looked up alongside the ISO 4217 codes.
+#[derive(Clone, Debug, Default)]
+pub struct CurrencyRegistry {
+    custom: BTreeMap<String, Arc<CustomCurrency>>,
+}
+
+impl CurrencyRegistry {
+    /// Creates a new, empty [CurrencyRegistry].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a custom currency, returning it as an [AnyCurrency].
+    ///
+    /// Codes must be unique, and must not shadow an ISO 4217 code.
+    pub fn register(&mut self, currency: CustomCurrency) -> Result<AnyCurrency, RegistryError> {
+        let code = currency.code();
+        let valid = (2..=MAX_CODE_LEN).contains(&code.len())
+            && code
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit());
+        if !valid {
+            return Err(RegistryError::InvalidCode);
+        }
+        if currency.minor_units() > Decimal::MAX_SCALE {
+            return Err(RegistryError::InvalidMinorUnits);
+        }
+        if let Some(iso) = iso(code) {
+            return Err(RegistryError::IsoCode(iso));
+        }
+        if self.custom.contains_key(code) {
+            return Err(RegistryError::Duplicate(code.into()));
+        }
+
+        let currency = Arc::new(currency);
+        self.custom
+            .insert(currency.code().into(), Arc::clone(&currency));
+
+        Ok(AnyCurrency::Custom(currency))
+    }
+
+    /// Gets an ISO 4217 or registered currency by code.
+    pub fn get(&self, code: &str) -> Option<AnyCurrency> {
+        iso(code).map(AnyCurrency::Iso).or_else(|| {
+            self.custom
+                .get(code)
+                .map(|c| AnyCurrency::Custom(Arc::clone(c)))
+        })
+    }
+
+    /// Gets the registered custom currencies, sorted by code.
+    pub fn custom(&self) -> impl Iterator<Item = &CustomCurrency> {
+        self.custom.values().map(|c| c.as_ref())
+    }
+
+    /// Gets the number of registered custom currencies.
+    pub fn len(&self) -> usize {
+        self.custom.len()
+    }
+
+    /// Gets whether no custom currencies are registered.
+    pub fn is_empty(&self) -> bool {
+        self.custom.is_empty()
+    }
+}
+
+fn iso(code: &str) -> Option<Currency> {
+    code.as_bytes()
+        .try_into()
+        .ok()
+        .and_then(Currency::from_alpha)
+}
+
+/// An amount of money in [AnyCurrency], stored as an integer number of minor units.
+///
+/// Shares the API of [Money], see [CurrencyUnit].
+pub type AnyMoney = Money<AnyCurrency>;
+
+impl AnyMoney {
+    /// Gets the [AnyCurrency].
+    pub fn currency(&self) -> &AnyCurrency {
+        let (currency, _) = self.parts();
+        currency
+    }
+
+    /// Gets the amount as [Money], `None` for custom currencies.
+    pub fn to_money(&self) -> Option<Money> {
+        self.currency()
+            .iso()
+            .map(|c| Money::from_minor(c, self.amount()))
+    }
+}
+
+impl From<Money> for AnyMoney {
+    fn from(val: Money) -> Self {
+        Self::from_minor(val.currency().into(), val.amount())
+    }
+}
+
+impl CurrencyUnit for AnyCurrency {
+    fn code(&self) -> &str {
+        AnyCurrency::code(self)
+    }
+
+    fn exponent(&self) -> u8 {
+        AnyCurrency::exponent(self)
+    }
+
+    fn iso(&self) -> Option<Currency> {
+        AnyCurrency::iso(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rates::{self, Rate};
+    use crate::{words, DecimalError, Rounding};
+    use alloc::string::ToString;
+
+    fn registry() -> CurrencyRegistry {
+        let mut registry = CurrencyRegistry::new();
+        registry
+            .register(CustomCurrency::new("BTC", "Bitcoin", 8).with_symbol("₿"))
+            .unwrap();
+        registry
+            .register(CustomCurrency::new("ETH", "Ether", 18).with_symbol("Ξ"))
+            .unwrap();
+        registry
+            .register(CustomCurrency::new("USDC", "USD Coin", 6))
+            .unwrap();
+        registry
+            .register(CustomCurrency::new("POINTS", "Loyalty points", 0))
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = registry();
+        assert_eq!(registry.len(), 4);
+
+        let btc = registry.get("BTC").unwrap();
+        assert_eq!(btc.code(), "BTC");
+        assert_eq!(btc.name(), "Bitcoin");
+        assert_eq!(btc.minor_units(), Some(8));
+        assert_eq!(btc.symbol(), Some("₿"));
+        assert_eq!(btc.iso(), None);
+
+        assert_eq!(registry.get("EUR"), Some(AnyCurrency::Iso(Currency::EUR)));
+        assert_eq!(registry.get("EUR").unwrap().symbol(), Some("€"));
+        assert_eq!(AnyCurrency::Iso(Currency::USD).symbol(), Some("$"));
+        assert_eq!(AnyCurrency::Iso(Currency::XAU).symbol(), None);
+        assert_eq!(registry.get("USDC").unwrap().symbol(), None);
+        assert_eq!(registry.get("DOGE"), None);
+
+        let codes: alloc::vec::Vec<&str> = registry.custom().map(|c| c.code()).collect();
+        assert_eq!(codes, ["BTC", "ETH", "POINTS", "USDC"]);
+
+        assert_eq!(
+            registry.register(CustomCurrency::new("USD", "Not a dollar", 2)),
+            Err(RegistryError::IsoCode(Currency::USD))
+        );
+        assert_eq!(
+            registry.register(CustomCurrency::new("BTC", "Bitcoin", 8)),
+            Err(RegistryError::Duplicate("BTC".into()))
+        );
+        assert_eq!(
+            registry.register(CustomCurrency::new("btc", "Bitcoin", 8)),
+            Err(RegistryError::InvalidCode)
+        );
+        assert_eq!(
+            registry.register(CustomCurrency::new("X", "Too short", 2)),
+            Err(RegistryError::InvalidCode)
+        );
+        assert_eq!(
+            registry.register(CustomCurrency::new("BIG", "Too precise", 39)),
+            Err(RegistryError::InvalidMinorUnits)
+        );
+    }
+
+    #[test]
+    fn test_any_money() {
+        let registry = registry();
+        let eth = registry.get("ETH").unwrap();
+
+        let one = AnyMoney::from_major(eth.clone(), 1).unwrap();
+        assert_eq!(one.amount(), 1_000_000_000_000_000_000);
+        assert_eq!(one.to_string(), "1.000000000000000000 ETH");
+
+        let gwei = AnyMoney::from_minor(eth.clone(), 1_000_000_000);
+        assert_eq!(
+            one.clone().checked_sub(gwei).unwrap().to_string(),
+            "0.999999999000000000 ETH"
+        );
+
+        let usdc = registry.get("USDC").unwrap();
+        let price: Decimal = "1.2345675".parse().unwrap();
+        assert_eq!(
+            AnyMoney::from_decimal(usdc.clone(), price, Rounding::HalfEven)
+                .unwrap()
+                .amount(),
+            1_234_568
+        );
+        assert_eq!(
+            AnyMoney::try_from_decimal(usdc.clone(), price),
+            Err(DecimalError::Precision)
+        );
+        assert_eq!(one.checked_add(AnyMoney::from_minor(usdc, 1)), None);
+
+        let points = AnyMoney::from_minor(registry.get("POINTS").unwrap(), 1500);
+        assert_eq!(points.to_string(), "1500 POINTS");
+        assert_eq!(points.to_money(), None);
+
+        let usd = AnyMoney::from(Money::from_minor(Currency::USD, 12345));
+        assert_eq!(usd.to_string(), "123.45 USD");
+        assert_eq!(
+            usd.to_money(),
+            Some(Money::from_minor(Currency::USD, 12345))
+        );
+    }
+
+    #[test]
+    fn test_format_and_convert() {
+        let registry = registry();
+        let btc = registry.get("BTC").unwrap();
+
+        let price: Decimal = "0.123456789".parse().unwrap();
+        assert_eq!(price.display_for(&btc).to_string(), "0.12345679");
+        assert_eq!(
+            price.round_for(&btc, Rounding::Down).unwrap().to_string(),
+            "0.12345678"
+        );
+        assert_eq!(
+            words::to_cheque_words(&words::English, &btc, 150_000_000),
+            "One BTC and 50000000/100000000"
+        );
+
+        // 1 BTC = 65000.50 USD
+        let rate: Rate = "65000.50".parse().unwrap();
+        let amount = AnyMoney::from_minor(btc.clone(), 12_345_678);
+        let usd = rates::convert(amount.clone(), Currency::USD, &rate, Rounding::HalfEven).unwrap();
+        assert_eq!(usd.to_string(), "8024.75 USD");
+
+        let back = rates::convert(usd, &btc, &rate.inverse(), Rounding::HalfEven).unwrap();
+        assert_eq!(back.to_string(), "0.12345674 BTC");
+        assert_eq!(
+            rates::convert(
+                amount,
+                registry.get("EUR").unwrap(),
+                &rate,
+                Rounding::HalfEven
+            )
+            .unwrap()
+            .to_string(),
+            "8024.75 EUR"
+        );
+    }
+}
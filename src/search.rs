@@ -0,0 +1,520 @@
+//! Fuzzy search over currency codes, names and symbols, "did you mean" suggestions, and lookup
+//! by name.
+//!
+//! ```
+//! use currency_iso4217::Currency;
+//!
+//! assert_eq!(Currency::suggest("UDS")[0], Currency::USD);
+//! assert_eq!(Currency::suggest("yen")[0], Currency::JPY);
+//! assert_eq!(Currency::suggest("€")[0], Currency::EUR);
+//!
+//! let err = "UDS".parse::<Currency>().unwrap_err();
+//! assert_eq!(err.suggestions()[0], Currency::USD);
+//!
+//! assert_eq!(Currency::from_name("Swiss franc"), [Currency::CHF]);
+//! assert!(Currency::from_name("peso").contains(&Currency::MXN));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::std::{fmt, str::FromStr};
+use crate::words::{English, Language};
+use crate::Currency;
+
+/// Maximum number of results returned by [Currency::suggest].
+pub const MAX_SUGGESTIONS: usize = 5;
+
+/// Scores for the kinds of match, higher is better.
+const EXACT_CODE: u32 = 1000;
+const EXACT_SYMBOL: u32 = 950;
+const EXACT_NAME: u32 = 900;
+const NAME_WORD: u32 = 800;
+const NAME_PREFIX: u32 = 700;
+const NAME_SUBSTRING: u32 = 600;
+const CODE_TYPO: u32 = 500;
+const NAME_TYPO: u32 = 400;
+
+/// A search result: the matching [Currency] and its score.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Match {
+    currency: Currency,
+    score: u32,
+}
+
+impl Match {
+    /// Gets the matching [Currency].
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Gets the score of the match, higher is better.
+    pub const fn score(&self) -> u32 {
+        self.score
+    }
+}
+
+/// Searches currency codes, names and symbols, returning matches ranked best first.
+///
+/// Matching is case-insensitive, except for symbols. Exact codes rank first, then exact
+/// [symbols](Currency::symbols) (e.g. `€`), exact names, whole words of names (e.g. `dollar`),
+/// name prefixes and substrings, and finally codes and name words within a small edit distance
+/// of the query to catch typos (e.g. `UDS` for `USD`). Equal scores are ranked by how widely the
+/// currency is traded, e.g. `USD` before `AUD` for `$`.
+pub fn search(query: &str) -> Vec<Match> {
+    let symbol = query.trim();
+    if symbol.is_empty() {
+        return Vec::new();
+    }
+    let query = fold(query);
+
+    let mut matches: Vec<Match> = Currency::LIST
+        .into_iter()
+        .filter_map(|currency| {
+            let score = score(&query, symbol, currency);
+            (score > 0).then_some(Match { currency, score })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| prominence(a.currency).cmp(&prominence(b.currency)))
+            .then_with(|| a.currency.alpha().cmp(b.currency.alpha()))
+    });
+
+    matches
+}
+
+/// Most traded currencies first, following the BIS Triennial Survey of foreign exchange turnover.
+const PROMINENCE: &[Currency] = &[
+    Currency::USD,
+    Currency::EUR,
+    Currency::JPY,
+    Currency::GBP,
+    Currency::CNY,
+    Currency::AUD,
+    Currency::CAD,
+    Currency::CHF,
+    Currency::HKD,
+    Currency::SGD,
+    Currency::SEK,
+    Currency::KRW,
+    Currency::NOK,
+    Currency::NZD,
+    Currency::INR,
+    Currency::MXN,
+    Currency::TWD,
+    Currency::ZAR,
+    Currency::BRL,
+    Currency::DKK,
+    Currency::PLN,
+    Currency::THB,
+    Currency::ILS,
+    Currency::IDR,
+    Currency::CZK,
+    Currency::AED,
+    Currency::TRY,
+    Currency::HUF,
+    Currency::CLP,
+    Currency::SAR,
+    Currency::PHP,
+    Currency::MYR,
+    Currency::COP,
+    Currency::RUB,
+    Currency::RON,
+    Currency::PEN,
+];
+
+/// Gets the rank of the currency in [PROMINENCE], after all listed currencies if not listed.
+fn prominence(currency: Currency) -> usize {
+    PROMINENCE
+        .iter()
+        .position(|&c| c == currency)
+        .unwrap_or(PROMINENCE.len())
+}
+
+impl Currency {
+    /// Gets up to [MAX_SUGGESTIONS] currencies matching the query, best first.
+    ///
+    /// See [search] for how matches are ranked. An exact code match is returned on its own.
+    pub fn suggest(query: &str) -> Vec<Self> {
+        let matches = search(query);
+        let len = match matches.first() {
+            Some(m) if m.score == EXACT_CODE => 1,
+            _ => MAX_SUGGESTIONS,
+        };
+
+        matches.into_iter().take(len).map(|m| m.currency).collect()
+    }
+}
+
+impl Currency {
+    /// Gets the currencies with the given English name.
+    ///
+    /// Matches official ISO 4217 names (`Pound Sterling`), common names (`British pound`,
+    /// `Japanese yen`) and unit names (`dollar`, `francs`), ignoring case, accents and
+    /// punctuation. Ambiguous names return all candidates sorted by code, unknown names return
+    /// an empty list.
+    pub fn from_name(name: &str) -> Vec<Self> {
+        let name = fold(name);
+        if name.is_empty() {
+            return Vec::new();
+        }
+
+        let exact: Vec<Self> = Self::LIST
+            .into_iter()
+            .filter(|c| fold(c.name()) == name)
+            .chain(
+                COMMON_NAMES
+                    .iter()
+                    .filter(|(common, _)| fold(common) == name)
+                    .map(|&(_, c)| c),
+            )
+            .collect();
+        if !exact.is_empty() {
+            return Self::LIST
+                .into_iter()
+                .filter(|c| exact.contains(c))
+                .collect();
+        }
+
+        Self::LIST
+            .into_iter()
+            .filter(|c| !c.is_fund())
+            .filter(|&c| {
+                English.unit_names(c).is_some_and(|units| {
+                    fold(units.major) == name || fold(units.major_plural) == name
+                })
+            })
+            .collect()
+    }
+}
+
+/// Widely used English names that differ from the official ISO 4217 names.
+const COMMON_NAMES: &[(&str, Currency)] = &[
+    ("American dollar", Currency::USD),
+    ("United States dollar", Currency::USD),
+    ("British pound", Currency::GBP),
+    ("Sterling", Currency::GBP),
+    ("Japanese yen", Currency::JPY),
+    ("Chinese yuan", Currency::CNY),
+    ("Renminbi", Currency::CNY),
+    ("South Korean won", Currency::KRW),
+    ("Russian rouble", Currency::RUB),
+    ("Emirati dirham", Currency::AED),
+    ("Polish zloty", Currency::PLN),
+    ("Hungarian forint", Currency::HUF),
+    ("Israeli new shekel", Currency::ILS),
+    ("Shekel", Currency::ILS),
+    ("Thai baht", Currency::THB),
+    ("South African rand", Currency::ZAR),
+    ("Indonesian rupiah", Currency::IDR),
+    ("Vietnamese dong", Currency::VND),
+    ("Nigerian naira", Currency::NGN),
+    ("Ukrainian hryvnia", Currency::UAH),
+    ("Kazakhstani tenge", Currency::KZT),
+    ("Bangladeshi taka", Currency::BDT),
+    ("Special drawing rights", Currency::XDR),
+];
+
+/// Lowercases, strips accents from Latin letters, and collapses punctuation and whitespace to
+/// single spaces.
+fn fold(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut space = false;
+
+    for c in s.chars().flat_map(char::to_lowercase) {
+        let c = match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+            'ç' | 'ć' | 'č' => 'c',
+            'ď' | 'đ' => 'd',
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' | 'ə' => 'e',
+            'ğ' => 'g',
+            'ì' | 'í' | 'î' | 'ï' | 'ī' | 'ı' => 'i',
+            'ł' => 'l',
+            'ñ' | 'ń' | 'ň' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => 'o',
+            'ř' => 'r',
+            'ś' | 'š' | 'ş' | 'ș' => 's',
+            'ť' | 'ţ' | 'ț' => 't',
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => 'u',
+            'ý' | 'ÿ' => 'y',
+            'ź' | 'ż' | 'ž' => 'z',
+            c => c,
+        };
+
+        if c.is_alphanumeric() {
+            if space && !out.is_empty() {
+                out.push(' ');
+            }
+            space = false;
+            out.push(c);
+        } else {
+            space = true;
+        }
+    }
+
+    out
+}
+
+/// Scores a currency against the folded query, and the trimmed query for symbols.
+fn score(query: &str, symbol: &str, currency: Currency) -> u32 {
+    let code = fold(currency.alpha());
+    let name = fold(currency.name());
+
+    if query == code {
+        return EXACT_CODE;
+    }
+    // symbols are mostly punctuation, which folding drops
+    if currency.symbols().contains(&symbol) {
+        return EXACT_SYMBOL;
+    }
+    if query.is_empty() {
+        return 0;
+    }
+    if query == name {
+        return EXACT_NAME;
+    }
+
+    let words = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty());
+    if words.clone().any(|w| w == query) {
+        return NAME_WORD;
+    }
+    if name.starts_with(query) || words.clone().any(|w| w.starts_with(query)) {
+        return NAME_PREFIX;
+    }
+    if query.chars().count() > 2 && name.contains(query) {
+        return NAME_SUBSTRING;
+    }
+
+    let len = query.chars().count();
+    if len == Currency::LEN {
+        let distance = edit_distance(query, &code);
+        if (1..=2).contains(&distance) {
+            // prefer codes sharing more letters with the query, e.g. `SDG` over `AED` for `UDS`
+            let common = query.chars().filter(|&c| code.contains(c)).count() as u32;
+            return CODE_TYPO - 100 * (distance as u32 - 1) + 10 * common;
+        }
+    }
+
+    let max_distance = match len {
+        0..=3 => 0,
+        4..=6 => 1,
+        _ => 2,
+    };
+    words
+        .map(|word| edit_distance(query, word))
+        .filter(|&distance| distance > 0 && distance <= max_distance)
+        .min()
+        .map_or(0, |distance| NAME_TYPO - 100 * (distance as u32 - 1))
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions and transpositions of
+/// adjacent characters each cost one.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev2: Vec<usize> = Vec::new();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = alloc::vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cur[j] = cur[j].min(prev2[j - 2] + 1);
+            }
+        }
+        prev2 = crate::std::mem::replace(&mut prev, crate::std::mem::take(&mut cur));
+        cur = alloc::vec![0; b.len() + 1];
+    }
+
+    prev[b.len()]
+}
+
+/// Error returned when parsing a [Currency] from an unknown alphabetic code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseCurrencyError {
+    input: String,
+}
+
+impl ParseCurrencyError {
+    /// Creates a new [ParseCurrencyError] for the rejected input.
+    pub fn new(input: &str) -> Self {
+        Self {
+            input: input.into(),
+        }
+    }
+
+    /// Gets the rejected input.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Gets "did you mean" suggestions for the rejected input, best first.
+    pub fn suggestions(&self) -> Vec<Currency> {
+        Currency::suggest(&self.input)
+    }
+}
+
+impl fmt::Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown currency code `{}`", self.input)?;
+        if let Some(best) = self.suggestions().first() {
+            write!(f, ", did you mean {}?", best.alpha())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCurrencyError {}
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    /// Parses a three-letter alphabetic code, ignoring ASCII case.
+    ///
+    /// Unlike the lenient `From<&str>` conversion, unknown codes are an error rather than
+    /// [Currency::XXX].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            [a, b, c] => Self::from_alpha(&[
+                a.to_ascii_uppercase(),
+                b.to_ascii_uppercase(),
+                c.to_ascii_uppercase(),
+            ]),
+            _ => None,
+        }
+        .ok_or_else(|| ParseCurrencyError::new(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("usd", "usd"), 0);
+        assert_eq!(edit_distance("uds", "usd"), 1);
+        assert_eq!(edit_distance("uds", "sdg"), 2);
+        assert_eq!(edit_distance("dolar", "dollar"), 1);
+        assert_eq!(edit_distance("", "eur"), 3);
+    }
+
+    #[test]
+    fn test_suggest() {
+        let uds = Currency::suggest("UDS");
+        assert_eq!(uds[0], Currency::USD);
+        assert_eq!(uds[1], Currency::UZS);
+        assert!(uds[2..]
+            .iter()
+            .all(|c| edit_distance("uds", &c.alpha().to_lowercase()) == 2));
+        assert_eq!(uds.len(), MAX_SUGGESTIONS);
+
+        assert_eq!(Currency::suggest("usd"), [Currency::USD]);
+        assert_eq!(Currency::suggest("yen")[0], Currency::JPY);
+        assert_eq!(Currency::suggest("Swiss Franc")[0], Currency::CHF);
+        assert!(Currency::suggest("").is_empty());
+
+        let dollars = search("dollar");
+        assert!(dollars.len() > MAX_SUGGESTIONS);
+        assert!(dollars.iter().all(|m| m.score() == NAME_WORD));
+        assert_eq!(dollars[0].currency(), Currency::USD);
+        assert_eq!(dollars[1].currency(), Currency::AUD);
+        assert_eq!(Currency::suggest("dolar")[0], Currency::USD);
+    }
+
+    #[test]
+    fn test_search_symbols() {
+        let dollars = search("$");
+        assert!(dollars.iter().all(|m| m.score() == EXACT_SYMBOL));
+        assert!(dollars
+            .iter()
+            .all(|m| m.currency().symbols().contains(&"$")));
+        assert_eq!(
+            Currency::suggest("$"),
+            [
+                Currency::USD,
+                Currency::AUD,
+                Currency::CAD,
+                Currency::HKD,
+                Currency::SGD
+            ]
+        );
+
+        assert_eq!(Currency::suggest("€"), [Currency::EUR]);
+        assert_eq!(Currency::suggest(" € "), [Currency::EUR]);
+        assert_eq!(Currency::suggest("¥"), [Currency::JPY, Currency::CNY]);
+        assert_eq!(Currency::suggest("£")[0], Currency::GBP);
+        assert_eq!(Currency::suggest("US$")[0], Currency::USD);
+        assert!(Currency::suggest("¤").is_empty());
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Currency::from_name("Swiss franc"), [Currency::CHF]);
+        assert_eq!(Currency::from_name("pound sterling"), [Currency::GBP]);
+        assert_eq!(Currency::from_name("POUND STERLING"), [Currency::GBP]);
+        assert_eq!(Currency::from_name("euro"), [Currency::EUR]);
+        assert_eq!(Currency::from_name("Japanese yen"), [Currency::JPY]);
+        assert_eq!(
+            Currency::from_name("Bolivar Soberano"),
+            [Currency::VED, Currency::VES]
+        );
+        assert_eq!(Currency::from_name("pa'anga"), [Currency::TOP]);
+        assert_eq!(Currency::from_name("  US   dollar "), [Currency::USD]);
+
+        let dollars = Currency::from_name("dollar");
+        assert!(dollars.contains(&Currency::USD));
+        assert!(dollars.contains(&Currency::AUD));
+        assert!(!dollars.contains(&Currency::USN));
+        assert!(dollars.windows(2).all(|w| w[0].alpha() < w[1].alpha()));
+
+        assert!(Currency::from_name("francs").contains(&Currency::XOF));
+        assert_eq!(
+            Currency::from_name("peso"),
+            [
+                Currency::ARS,
+                Currency::CLP,
+                Currency::COP,
+                Currency::CUP,
+                Currency::DOP,
+                Currency::MXN,
+                Currency::PHP,
+                Currency::UYU
+            ]
+        );
+
+        assert!(Currency::from_name("").is_empty());
+        assert!(Currency::from_name("doubloon").is_empty());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!("usd".parse::<Currency>(), Ok(Currency::USD));
+        assert_eq!("EUR".parse::<Currency>(), Ok(Currency::EUR));
+
+        let err = "UDS".parse::<Currency>().unwrap_err();
+        assert_eq!(err.input(), "UDS");
+        assert_eq!(err.suggestions()[0], Currency::USD);
+        assert_eq!(
+            err.to_string(),
+            "unknown currency code `UDS`, did you mean USD?"
+        );
+
+        assert!("USDX".parse::<Currency>().is_err());
+        assert_eq!(
+            "##".parse::<Currency>().unwrap_err().to_string(),
+            "unknown currency code `##`"
+        );
+    }
+}
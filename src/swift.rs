@@ -0,0 +1,428 @@
+//! SWIFT MT currency-amount fields: `32A` (value date, currency, amount), `33B` (instructed
+//! currency and amount) and `71F` (sender's charges).
+//!
+//! Amounts use the SWIFT `15d` format: at most 15 characters, a mandatory comma as the decimal
+//! separator, and no more decimal digits than the minor units of the currency.
+//!
+//! ```
+//! use currency_iso4217::swift::Field;
+//! use currency_iso4217::{Currency, Money};
+//!
+//! let field: Field = ":32A:240115USD1234,56".parse().unwrap();
+//! assert_eq!(field.money(), Money::from_minor(Currency::USD, 123456));
+//! assert_eq!(field.to_string(), ":32A:240115USD1234,56");
+//!
+//! assert!(":33B:JPY1500,5".parse::<Field>().is_err());
+//! ```
+
+use crate::date::parse_digits;
+use crate::std::{fmt, str::FromStr};
+use crate::{Currency, Date, Money};
+
+/// Maximum length of a `15d` amount, including the comma.
+pub const MAX_AMOUNT_LEN: usize = 15;
+
+/// Errors returned when parsing or formatting SWIFT currency-amount fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SwiftError {
+    /// The field tag is missing or not `32A`, `33B` or `71F`.
+    InvalidTag,
+    /// The value date is not a valid `YYMMDD` date.
+    InvalidDate,
+    /// The currency code is unknown.
+    InvalidCurrency,
+    /// The amount is not digits with a comma decimal separator.
+    InvalidAmount,
+    /// The amount is negative.
+    Negative,
+    /// The amount is longer than [MAX_AMOUNT_LEN] characters.
+    TooLong,
+    /// The amount has more decimal digits than the minor units of the currency.
+    Precision(Currency),
+}
+
+impl fmt::Display for SwiftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTag => write!(f, "invalid field tag"),
+            Self::InvalidDate => write!(f, "invalid value date"),
+            Self::InvalidCurrency => write!(f, "invalid currency code"),
+            Self::InvalidAmount => write!(f, "invalid amount"),
+            Self::Negative => write!(f, "amount must not be negative"),
+            Self::TooLong => write!(f, "amount exceeds {MAX_AMOUNT_LEN} characters"),
+            Self::Precision(c) => write!(
+                f,
+                "amount exceeds {} decimal digits allowed for {}",
+                c.exponent(),
+                c.alpha()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SwiftError {}
+
+/// Parses a `15d` amount, e.g. `1234,56`, in the given currency.
+pub fn parse_amount(s: &str, currency: Currency) -> Result<Money, SwiftError> {
+    if s.len() > MAX_AMOUNT_LEN {
+        return Err(SwiftError::TooLong);
+    }
+
+    let (int, frac) = s.split_once(',').ok_or(SwiftError::InvalidAmount)?;
+    let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if int.is_empty() || !digits(int) || !digits(frac) {
+        return Err(SwiftError::InvalidAmount);
+    }
+
+    // every digit after the comma counts against the decimals of the currency
+    let exp = currency.exponent() as usize;
+    if frac.len() > exp {
+        return Err(SwiftError::Precision(currency));
+    }
+
+    // at most 14 digits, so this cannot overflow
+    let amount = int
+        .bytes()
+        .chain(frac.bytes())
+        .chain(crate::std::iter::repeat(b'0').take(exp - frac.len()))
+        .fold(0i128, |acc, d| acc * 10 + (d - b'0') as i128);
+
+    Ok(Money::from_minor(currency, amount))
+}
+
+/// Writes a `15d` amount, e.g. `1234,56`, with all the minor unit digits of the currency.
+pub fn write_amount(f: &mut dyn fmt::Write, money: Money) -> fmt::Result {
+    let exp = money.currency().exponent() as u32;
+    let pow = 10i128.pow(exp);
+    write!(f, "{},", money.amount() / pow)?;
+    if exp > 0 {
+        write!(f, "{:0width$}", money.amount() % pow, width = exp as usize)?;
+    }
+    Ok(())
+}
+
+/// Checks that an amount can be written as a `15d` amount.
+fn check_amount(money: Money) -> Result<Money, SwiftError> {
+    if money.amount() < 0 {
+        return Err(SwiftError::Negative);
+    }
+
+    let digits = money.amount().checked_ilog10().unwrap_or(0) as usize + 1;
+    let exp = money.currency().exponent() as usize;
+    // integer digits (at least one), comma, and decimal digits
+    if digits.max(exp + 1) + 1 > MAX_AMOUNT_LEN {
+        Err(SwiftError::TooLong)
+    } else {
+        Ok(money)
+    }
+}
+
+fn parse_currency(s: &str) -> Result<Currency, SwiftError> {
+    match s.as_bytes() {
+        [a, b, c] => Currency::from_alpha(&[*a, *b, *c]).ok_or(SwiftError::InvalidCurrency),
+        _ => Err(SwiftError::InvalidCurrency),
+    }
+}
+
+/// A currency and amount, the `3!a15d` format of fields `33B` and `71F`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CurrencyAmount(Money);
+
+impl CurrencyAmount {
+    /// Creates a new [CurrencyAmount], checking that the amount fits the `15d` format.
+    pub fn new(money: Money) -> Result<Self, SwiftError> {
+        check_amount(money).map(Self)
+    }
+
+    /// Gets the amount.
+    pub const fn money(&self) -> Money {
+        self.0
+    }
+}
+
+impl FromStr for CurrencyAmount {
+    type Err = SwiftError;
+
+    /// Parses e.g. `USD1234,56`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (currency, amount) = s
+            .get(..Currency::LEN)
+            .zip(s.get(Currency::LEN..))
+            .ok_or(SwiftError::InvalidCurrency)?;
+        parse_amount(amount, parse_currency(currency)?).map(Self)
+    }
+}
+
+impl fmt::Display for CurrencyAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.currency().alpha())?;
+        write_amount(f, self.0)
+    }
+}
+
+/// A value date, currency and amount, the `6!n3!a15d` format of field `32A`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ValueDateAmount {
+    value_date: Date,
+    amount: CurrencyAmount,
+}
+
+impl ValueDateAmount {
+    /// Creates a new [ValueDateAmount], checking that the date is between 2000 and 2099, and the
+    /// amount fits the `15d` format.
+    pub fn new(value_date: Date, money: Money) -> Result<Self, SwiftError> {
+        if !(2000..=2099).contains(&value_date.year()) {
+            return Err(SwiftError::InvalidDate);
+        }
+        Ok(Self {
+            value_date,
+            amount: CurrencyAmount::new(money)?,
+        })
+    }
+
+    /// Gets the value date.
+    pub const fn value_date(&self) -> Date {
+        self.value_date
+    }
+
+    /// Gets the amount.
+    pub const fn money(&self) -> Money {
+        self.amount.money()
+    }
+}
+
+impl FromStr for ValueDateAmount {
+    type Err = SwiftError;
+
+    /// Parses e.g. `240115USD1234,56`, with years `YY` in 2000-2099.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, rest) = s.get(..6).zip(s.get(6..)).ok_or(SwiftError::InvalidDate)?;
+        let date = date.as_bytes();
+        let [year, month, day] = [&date[..2], &date[2..4], &date[4..]]
+            .map(|d| parse_digits(d).ok_or(SwiftError::InvalidDate));
+        let value_date =
+            Date::new(2000 + year?, month? as u8, day? as u8).ok_or(SwiftError::InvalidDate)?;
+
+        Ok(Self {
+            value_date,
+            amount: rest.parse()?,
+        })
+    }
+}
+
+impl fmt::Display for ValueDateAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.value_date;
+        write!(
+            f,
+            "{:02}{:02}{:02}{}",
+            date.year() % 100,
+            date.month(),
+            date.day(),
+            self.amount
+        )
+    }
+}
+
+/// A SWIFT MT currency-amount field, with its tag.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Field {
+    /// `:32A:` value date, currency code, amount.
+    ValueDateCurrencyAmount(ValueDateAmount),
+    /// `:33B:` currency, instructed amount.
+    InstructedAmount(CurrencyAmount),
+    /// `:71F:` sender's charges.
+    SendersCharges(CurrencyAmount),
+}
+
+impl Field {
+    /// Gets the field tag, e.g. `32A`.
+    pub const fn tag(&self) -> &'static str {
+        match self {
+            Self::ValueDateCurrencyAmount(_) => "32A",
+            Self::InstructedAmount(_) => "33B",
+            Self::SendersCharges(_) => "71F",
+        }
+    }
+
+    /// Gets the amount.
+    pub const fn money(&self) -> Money {
+        match self {
+            Self::ValueDateCurrencyAmount(v) => v.money(),
+            Self::InstructedAmount(v) | Self::SendersCharges(v) => v.money(),
+        }
+    }
+}
+
+impl FromStr for Field {
+    type Err = SwiftError;
+
+    /// Parses a tagged field, e.g. `:32A:240115USD1234,56`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix(':').ok_or(SwiftError::InvalidTag)?;
+        let (tag, value) = s.split_once(':').ok_or(SwiftError::InvalidTag)?;
+        let value = value.trim_end_matches(['\r', '\n']);
+
+        match tag {
+            "32A" => value.parse().map(Self::ValueDateCurrencyAmount),
+            "33B" => value.parse().map(Self::InstructedAmount),
+            "71F" => value.parse().map(Self::SendersCharges),
+            _ => Err(SwiftError::InvalidTag),
+        }
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ":{}:", self.tag())?;
+        match self {
+            Self::ValueDateCurrencyAmount(v) => write!(f, "{v}"),
+            Self::InstructedAmount(v) | Self::SendersCharges(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn test_parse_amount() {
+        let usd = |amount| Ok(Money::from_minor(Currency::USD, amount));
+
+        assert_eq!(parse_amount("1234,56", Currency::USD), usd(123456));
+        assert_eq!(parse_amount("1234,5", Currency::USD), usd(123450));
+        assert_eq!(parse_amount("1234,", Currency::USD), usd(123400));
+        assert_eq!(parse_amount("0,01", Currency::USD), usd(1));
+        assert_eq!(
+            parse_amount("1,500", Currency::USD),
+            Err(SwiftError::Precision(Currency::USD))
+        );
+        assert_eq!(
+            parse_amount("1500,00", Currency::JPY),
+            Err(SwiftError::Precision(Currency::JPY))
+        );
+        assert_eq!(
+            parse_amount("1500,", Currency::JPY),
+            Ok(Money::from_minor(Currency::JPY, 1500))
+        );
+        assert_eq!(
+            parse_amount("1,234", Currency::KWD),
+            Ok(Money::from_minor(Currency::KWD, 1234))
+        );
+
+        assert_eq!(
+            parse_amount("1234,567", Currency::USD),
+            Err(SwiftError::Precision(Currency::USD))
+        );
+        assert_eq!(
+            parse_amount("1500,5", Currency::JPY),
+            Err(SwiftError::Precision(Currency::JPY))
+        );
+        assert_eq!(
+            parse_amount("1234.56", Currency::USD),
+            Err(SwiftError::InvalidAmount)
+        );
+        assert_eq!(
+            parse_amount(",56", Currency::USD),
+            Err(SwiftError::InvalidAmount)
+        );
+        assert_eq!(
+            parse_amount("-1,00", Currency::USD),
+            Err(SwiftError::InvalidAmount)
+        );
+        assert_eq!(
+            parse_amount("1,2,3", Currency::USD),
+            Err(SwiftError::InvalidAmount)
+        );
+        assert_eq!(
+            parse_amount("12345678901234,5", Currency::USD),
+            Err(SwiftError::TooLong)
+        );
+        assert_eq!(
+            parse_amount("12345678901234,", Currency::JPY),
+            Ok(Money::from_minor(Currency::JPY, 12_345_678_901_234))
+        );
+    }
+
+    #[test]
+    fn test_write_amount() {
+        let format = |money| {
+            let mut out = String::new();
+            write_amount(&mut out, money).unwrap();
+            out
+        };
+
+        assert_eq!(format(Money::from_minor(Currency::USD, 123456)), "1234,56");
+        assert_eq!(format(Money::from_minor(Currency::USD, 5)), "0,05");
+        assert_eq!(format(Money::from_minor(Currency::JPY, 1500)), "1500,");
+        assert_eq!(format(Money::from_minor(Currency::KWD, 1234)), "1,234");
+    }
+
+    #[test]
+    fn test_fields() {
+        let field: Field = ":32A:240115USD1234,56".parse().unwrap();
+        assert_eq!(field.tag(), "32A");
+        assert_eq!(field.money(), Money::from_minor(Currency::USD, 123456));
+        assert_eq!(field.to_string(), ":32A:240115USD1234,56");
+        match field {
+            Field::ValueDateCurrencyAmount(v) => {
+                assert_eq!(v.value_date(), Date::new(2024, 1, 15).unwrap())
+            }
+            _ => panic!("expected 32A"),
+        }
+
+        let field: Field = ":33B:EUR1000,\r\n".parse().unwrap();
+        assert_eq!(field.to_string(), ":33B:EUR1000,00");
+
+        let field: Field = ":71F:GBP5,5".parse().unwrap();
+        assert_eq!(
+            field,
+            Field::SendersCharges(
+                CurrencyAmount::new(Money::from_minor(Currency::GBP, 550)).unwrap()
+            )
+        );
+
+        assert_eq!(
+            ":32A:241315USD1,".parse::<Field>(),
+            Err(SwiftError::InvalidDate)
+        );
+        assert_eq!(":32A:2401".parse::<Field>(), Err(SwiftError::InvalidDate));
+        assert_eq!(
+            ":33B:usd1,".parse::<Field>(),
+            Err(SwiftError::InvalidCurrency)
+        );
+        assert_eq!(
+            ":33B:QQQ1,".parse::<Field>(),
+            Err(SwiftError::InvalidCurrency)
+        );
+        assert_eq!(":32B:USD1,".parse::<Field>(), Err(SwiftError::InvalidTag));
+        assert_eq!("33B:USD1,".parse::<Field>(), Err(SwiftError::InvalidTag));
+        assert_eq!(
+            ":71F:BHD1,2345".parse::<Field>(),
+            Err(SwiftError::Precision(Currency::BHD))
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            CurrencyAmount::new(Money::from_minor(Currency::USD, -1)),
+            Err(SwiftError::Negative)
+        );
+        // 12 integer digits, comma and 2 decimals
+        assert!(CurrencyAmount::new(Money::from_minor(Currency::USD, 99_999_999_999_999)).is_ok());
+        assert_eq!(
+            CurrencyAmount::new(Money::from_minor(Currency::USD, 100_000_000_000_000)),
+            Err(SwiftError::TooLong)
+        );
+
+        let date = Date::new(1999, 12, 31).unwrap();
+        assert_eq!(
+            ValueDateAmount::new(date, Money::from_minor(Currency::USD, 1)),
+            Err(SwiftError::InvalidDate)
+        );
+    }
+}
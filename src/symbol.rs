@@ -0,0 +1,172 @@
+//! Currency symbols, e.g. `$` and `€`.
+//!
+//! Symbols are listed for widely traded and commonly displayed currencies; currencies without a
+//! well-established symbol, funds and precious metals have none. Many symbols are shared, e.g.
+//! `$` and `kr`, so each currency also lists a disambiguated form where one is in common use,
+//! e.g. `A$` for the Australian dollar.
+//!
+//! ```
+//! use currency_iso4217::Currency;
+//!
+//! assert_eq!(Currency::EUR.symbol(), Some("€"));
+//! assert_eq!(Currency::AUD.symbols(), ["$", "A$"]);
+//! assert_eq!(Currency::XAU.symbol(), None);
+//! ```
+
+use crate::Currency;
+
+impl Currency {
+    /// Gets the symbols of the currency, the local symbol first.
+    ///
+    /// Empty for currencies without symbol data.
+    pub const fn symbols(&self) -> &'static [&'static str] {
+        match self {
+            Self::AED => &["د.إ"],
+            Self::AFN => &["؋"],
+            Self::ALL => &["L"],
+            Self::AMD => &["֏"],
+            Self::ANG => &["ƒ", "NAƒ"],
+            Self::ARS => &["$", "AR$"],
+            Self::AUD => &["$", "A$"],
+            Self::AWG => &["ƒ", "Afl."],
+            Self::AZN => &["₼"],
+            Self::BAM => &["KM"],
+            Self::BBD => &["$", "Bds$"],
+            Self::BDT => &["৳"],
+            Self::BGN => &["лв"],
+            Self::BMD => &["$", "BD$"],
+            Self::BND => &["$", "B$"],
+            Self::BOB => &["Bs"],
+            Self::BRL => &["R$"],
+            Self::BSD => &["$", "B$"],
+            Self::BWP => &["P"],
+            Self::BYN => &["Br"],
+            Self::BZD => &["$", "BZ$"],
+            Self::CAD => &["$", "C$", "CA$"],
+            Self::CHF => &["Fr.", "CHF"],
+            Self::CLP => &["$", "CLP$"],
+            Self::CNY => &["¥", "CN¥", "元"],
+            Self::COP => &["$", "COL$"],
+            Self::CRC => &["₡"],
+            Self::CUP => &["$", "$MN"],
+            Self::CZK => &["Kč"],
+            Self::DKK => &["kr.", "kr"],
+            Self::DOP => &["$", "RD$"],
+            Self::EGP => &["E£", "ج.م"],
+            Self::ETB => &["Br"],
+            Self::EUR => &["€"],
+            Self::FJD => &["$", "FJ$"],
+            Self::FKP => &["£"],
+            Self::GBP => &["£"],
+            Self::GEL => &["₾"],
+            Self::GHS => &["₵", "GH₵"],
+            Self::GIP => &["£"],
+            Self::GTQ => &["Q"],
+            Self::GYD => &["$", "G$"],
+            Self::HKD => &["$", "HK$"],
+            Self::HNL => &["L"],
+            Self::HTG => &["G"],
+            Self::HUF => &["Ft"],
+            Self::IDR => &["Rp"],
+            Self::ILS => &["₪"],
+            Self::INR => &["₹"],
+            Self::ISK => &["kr"],
+            Self::JMD => &["$", "J$"],
+            Self::JPY => &["¥", "円"],
+            Self::KES => &["KSh"],
+            Self::KHR => &["៛"],
+            Self::KPW => &["₩"],
+            Self::KRW => &["₩"],
+            Self::KYD => &["$", "CI$"],
+            Self::KZT => &["₸"],
+            Self::LAK => &["₭"],
+            Self::LKR => &["Rs", "₨"],
+            Self::LRD => &["$", "L$"],
+            Self::MKD => &["ден"],
+            Self::MMK => &["K"],
+            Self::MNT => &["₮"],
+            Self::MOP => &["MOP$"],
+            Self::MUR => &["Rs", "₨"],
+            Self::MWK => &["MK"],
+            Self::MXN => &["$", "MX$"],
+            Self::MYR => &["RM"],
+            Self::NAD => &["$", "N$"],
+            Self::NGN => &["₦"],
+            Self::NIO => &["C$"],
+            Self::NOK => &["kr"],
+            Self::NPR => &["Rs", "₨"],
+            Self::NZD => &["$", "NZ$"],
+            Self::PAB => &["B/."],
+            Self::PEN => &["S/"],
+            Self::PGK => &["K"],
+            Self::PHP => &["₱"],
+            Self::PKR => &["Rs", "₨"],
+            Self::PLN => &["zł"],
+            Self::PYG => &["₲"],
+            Self::QAR => &["ر.ق"],
+            Self::RON => &["lei"],
+            Self::RSD => &["дин."],
+            Self::RUB => &["₽"],
+            Self::SAR => &["﷼", "ر.س"],
+            Self::SBD => &["$", "SI$"],
+            Self::SEK => &["kr"],
+            Self::SGD => &["$", "S$"],
+            Self::SHP => &["£"],
+            Self::SRD => &["$", "Sr$"],
+            Self::SVC => &["₡"],
+            Self::THB => &["฿"],
+            Self::TOP => &["T$"],
+            Self::TRY => &["₺"],
+            Self::TTD => &["$", "TT$"],
+            Self::TWD => &["$", "NT$"],
+            Self::TZS => &["TSh"],
+            Self::UAH => &["₴"],
+            Self::UGX => &["USh"],
+            Self::USD => &["$", "US$"],
+            Self::UYU => &["$", "$U"],
+            Self::VND => &["₫"],
+            Self::VUV => &["VT"],
+            Self::XAF => &["FCFA"],
+            Self::XCD => &["$", "EC$"],
+            Self::XOF => &["CFA"],
+            Self::ZAR => &["R"],
+            Self::ZMW => &["K", "ZK"],
+            _ => &[],
+        }
+    }
+
+    /// Gets the local symbol of the currency, e.g. `$` for [Currency::USD].
+    pub const fn symbol(&self) -> Option<&'static str> {
+        match self.symbols() {
+            [symbol, ..] => Some(symbol),
+            [] => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbols() {
+        assert_eq!(Currency::USD.symbol(), Some("$"));
+        assert_eq!(Currency::GBP.symbol(), Some("£"));
+        assert_eq!(Currency::JPY.symbols(), ["¥", "円"]);
+        assert_eq!(Currency::CHF.symbol(), Some("Fr."));
+        assert!(Currency::USN.symbols().is_empty());
+
+        for cur in Currency::LIST {
+            let symbols = cur.symbols();
+            assert!(symbols.iter().all(|s| !s.is_empty() && s.trim() == *s));
+            assert!(
+                symbols
+                    .iter()
+                    .enumerate()
+                    .all(|(i, s)| !symbols[..i].contains(s)),
+                "{} has duplicate symbols",
+                cur.alpha()
+            );
+        }
+    }
+}
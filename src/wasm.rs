@@ -0,0 +1,160 @@
+//! WebAssembly bindings, for use from JavaScript through `wasm-bindgen`.
+//!
+//! Currencies are passed as alphabetic codes, typed as the `Currency` string union from
+//! `src/generated.d.ts` in the TypeScript definitions. Lookups ignore ASCII case, except
+//! `isCurrency`, which only accepts the codes in the union. Amounts are in minor units, as
+//! `bigint`.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{Currency, Decimal, Money, ParseCurrencyError};
+
+#[wasm_bindgen(typescript_custom_section)]
+const CURRENCY_TS: &str = include_str!("generated.d.ts");
+
+fn lookup(code: &str) -> Result<Currency, ParseCurrencyError> {
+    code.parse()
+}
+
+/// Gets whether `code` is an ISO 4217 alphabetic code, in upper case.
+#[wasm_bindgen(js_name = isCurrency)]
+pub fn is_currency(code: &str) -> bool {
+    <&[u8; 3]>::try_from(code.as_bytes()).is_ok_and(|code| Currency::from_alpha(code).is_some())
+}
+
+/// Parses an alphabetic code, ignoring ASCII case, e.g. `"usd"` as `"USD"`.
+#[wasm_bindgen(js_name = parseCurrency, unchecked_return_type = "Currency | undefined")]
+pub fn parse_currency(code: &str) -> Option<String> {
+    lookup(code).ok().map(|cur| cur.alpha().into())
+}
+
+/// Looks up a currency by its numeric code.
+#[wasm_bindgen(js_name = fromNumeric, unchecked_return_type = "Currency | undefined")]
+pub fn from_numeric(numeric: u32) -> Option<String> {
+    Currency::from_numeric(numeric).map(|cur| cur.alpha().into())
+}
+
+/// Gets all currencies, sorted by alphabetic code.
+#[wasm_bindgen(unchecked_return_type = "Currency[]")]
+pub fn currencies() -> Vec<String> {
+    Currency::LIST
+        .iter()
+        .map(|cur| cur.alpha().into())
+        .collect()
+}
+
+/// Gets the numeric code of a currency.
+#[wasm_bindgen(js_name = numericCode)]
+pub fn numeric_code(#[wasm_bindgen(unchecked_param_type = "Currency")] code: &str) -> Option<u32> {
+    lookup(code).ok().map(u32::from)
+}
+
+/// Gets the name of a currency (in English).
+#[wasm_bindgen(js_name = currencyName)]
+pub fn currency_name(
+    #[wasm_bindgen(unchecked_param_type = "Currency")] code: &str,
+) -> Option<String> {
+    lookup(code).ok().map(|cur| cur.name().into())
+}
+
+/// Gets the number of minor units of a currency, `undefined` where not applicable.
+#[wasm_bindgen(js_name = minorUnits)]
+pub fn minor_units(#[wasm_bindgen(unchecked_param_type = "Currency")] code: &str) -> Option<u8> {
+    lookup(code).ok().and_then(|cur| cur.minor_units())
+}
+
+/// Gets whether the code is a funds code rather than a currency.
+#[wasm_bindgen(js_name = isFund)]
+pub fn is_fund(#[wasm_bindgen(unchecked_param_type = "Currency")] code: &str) -> bool {
+    lookup(code).is_ok_and(|cur| cur.is_fund())
+}
+
+/// Gets the names of the countries and other entities using a currency.
+#[wasm_bindgen]
+pub fn countries(#[wasm_bindgen(unchecked_param_type = "Currency")] code: &str) -> Vec<String> {
+    lookup(code)
+        .map(|cur| cur.countries().iter().map(|&c| c.into()).collect())
+        .unwrap_or_default()
+}
+
+/// Formats an amount in minor units, e.g. `12345n` USD as `"123.45 USD"`.
+#[wasm_bindgen(js_name = formatAmount)]
+pub fn format_amount(
+    #[wasm_bindgen(unchecked_param_type = "Currency")] code: &str,
+    minor: i64,
+) -> Result<String, JsError> {
+    let currency = lookup(code)?;
+    Ok(Money::from_minor(currency, minor.into()).to_string())
+}
+
+/// Formats an amount in minor units as a plain decimal number, e.g. `12345n` USD as `"123.45"`.
+#[wasm_bindgen(js_name = formatDecimal)]
+pub fn format_decimal(
+    #[wasm_bindgen(unchecked_param_type = "Currency")] code: &str,
+    minor: i64,
+) -> Result<String, JsError> {
+    let currency = lookup(code)?;
+    Ok(Money::from_minor(currency, minor.into())
+        .to_decimal()
+        .to_string())
+}
+
+/// Parses a plain decimal amount, e.g. `"123.45"`, into minor units of a currency.
+///
+/// Throws if the amount has more decimal places than the currency allows.
+#[wasm_bindgen(js_name = toMinorUnits)]
+pub fn to_minor_units(
+    #[wasm_bindgen(unchecked_param_type = "Currency")] code: &str,
+    amount: &str,
+) -> Result<i64, JsError> {
+    let currency = lookup(code)?;
+    let value: Decimal = amount.parse()?;
+    let money = Money::try_from_decimal(currency, value)?;
+    i64::try_from(money.amount()).map_err(|_| JsError::new("amount out of range"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        assert!(is_currency("USD"));
+        assert!(!is_currency("usd"));
+        assert!(!is_currency("QQQ"));
+        assert!(!is_currency("EURO"));
+
+        assert_eq!(parse_currency("chf").as_deref(), Some("CHF"));
+        assert_eq!(parse_currency("QQQ"), None);
+        assert_eq!(from_numeric(978).as_deref(), Some("EUR"));
+        assert_eq!(from_numeric(1), None);
+
+        let all = currencies();
+        assert_eq!(all.len(), Currency::LIST.len());
+        assert!(all.iter().all(|code| is_currency(code)));
+    }
+
+    #[test]
+    fn test_metadata() {
+        assert_eq!(numeric_code("USD"), Some(840));
+        assert_eq!(currency_name("jpy").as_deref(), Some("Yen"));
+        assert_eq!(minor_units("KWD"), Some(3));
+        assert_eq!(minor_units("XAU"), None);
+        assert!(is_fund("USN"));
+        assert!(!is_fund("USD"));
+        assert!(countries("CHF").iter().any(|c| c == "SWITZERLAND"));
+        assert!(countries("QQQ").is_empty());
+    }
+
+    #[test]
+    fn test_amounts() {
+        assert_eq!(format_amount("USD", 12345).unwrap(), "123.45 USD");
+        assert_eq!(format_amount("JPY", -500).unwrap(), "-500 JPY");
+        assert_eq!(format_decimal("KWD", 1005).unwrap(), "1.005");
+        assert_eq!(to_minor_units("USD", "123.45").unwrap(), 12345);
+        assert_eq!(to_minor_units("jpy", "500").unwrap(), 500);
+    }
+}
@@ -0,0 +1,497 @@
+//! Spell out currency amounts in words, e.g. for cheques and legal documents.
+//!
+//! Amounts are given in minor units of the [Currency], so `12345` is `123.45` USD, `12345` JPY,
+//! and `12.345` KWD.
+//!
+//! ```
+//! use currency_iso4217::{words, Currency};
+//!
+//! assert_eq!(
+//!     words::to_cheque_words(&words::English, Currency::USD, 12345),
+//!     "One hundred twenty-three dollars and 45/100",
+//! );
+//! assert_eq!(
+//!     words::to_words(&words::English, Currency::USD, 12345),
+//!     "One hundred twenty-three dollars and forty-five cents",
+//! );
+//! ```
+//!
+//! Other languages can be supported by implementing the [Language] trait. Custom currencies,
+//! e.g. from a [CurrencyRegistry](crate::CurrencyRegistry), are spelled out with their code.
+
+use alloc::string::String;
+
+use crate::std::fmt::{self, Write};
+use crate::{Currency, CurrencyUnit};
+
+/// Names of the major and minor units of a currency in a given language.
+///
+/// Empty minor unit names mean the minor unit is not spoken, e.g. for currencies whose subunit
+/// is not a hundredth, and the minor part is written as a fraction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnitNames {
+    /// Singular name of the major unit, e.g. `dollar`.
+    pub major: &'static str,
+    /// Plural name of the major unit, e.g. `dollars`.
+    pub major_plural: &'static str,
+    /// Singular name of the minor unit, e.g. `cent`.
+    pub minor: &'static str,
+    /// Plural name of the minor unit, e.g. `cents`.
+    pub minor_plural: &'static str,
+}
+
+impl UnitNames {
+    /// Creates a new [UnitNames].
+    pub const fn new(
+        major: &'static str,
+        major_plural: &'static str,
+        minor: &'static str,
+        minor_plural: &'static str,
+    ) -> Self {
+        Self {
+            major,
+            major_plural,
+            minor,
+            minor_plural,
+        }
+    }
+
+    /// Creates a new [UnitNames] without spoken minor unit names.
+    pub const fn major_only(major: &'static str, major_plural: &'static str) -> Self {
+        Self::new(major, major_plural, "", "")
+    }
+}
+
+/// Style used to spell out the minor part of an amount.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Style {
+    /// Minor units spelled out in words: `... dollars and forty-five cents`.
+    #[default]
+    Words,
+    /// Minor units written as a fraction, as is customary on cheques: `... dollars and 45/100`.
+    Cheque,
+}
+
+/// Language-specific rules for spelling out amounts.
+pub trait Language {
+    /// Writes the cardinal number `n` in lower-case words.
+    fn write_number(&self, f: &mut dyn fmt::Write, n: u128) -> fmt::Result;
+
+    /// Gets the names of the currency units, if known for this language.
+    fn unit_names(&self, currency: Currency) -> Option<UnitNames>;
+
+    /// Gets the word joining the major and minor parts of an amount.
+    fn conjunction(&self) -> &'static str;
+}
+
+/// Writes `amount` (in minor units of `currency`) in words, capitalizing the first letter.
+///
+/// Currencies without known unit names for the language use the code as the unit name, and the
+/// [Style::Cheque] fraction for the minor part, as do currencies without spoken minor units.
+pub fn write_words<L: Language + ?Sized, C: CurrencyUnit>(
+    lang: &L,
+    f: &mut dyn fmt::Write,
+    currency: C,
+    amount: u128,
+    style: Style,
+) -> fmt::Result {
+    let mut f = Capitalize {
+        inner: f,
+        first: true,
+    };
+    let exp = currency.exponent() as u32;
+    let scale = 10u128.pow(exp);
+    let (major, minor) = (amount / scale, amount % scale);
+    let names = currency.iso().and_then(|c| lang.unit_names(c));
+
+    lang.write_number(&mut f, major)?;
+    match names {
+        Some(n) if major == 1 => write!(f, " {}", n.major)?,
+        Some(n) => write!(f, " {}", n.major_plural)?,
+        None => write!(f, " {}", currency.code())?,
+    }
+
+    if exp == 0 {
+        return Ok(());
+    }
+
+    match (style, names) {
+        (Style::Words, Some(n)) if !n.minor.is_empty() => {
+            if minor != 0 {
+                write!(f, " {} ", lang.conjunction())?;
+                lang.write_number(&mut f, minor)?;
+                let name = if minor == 1 { n.minor } else { n.minor_plural };
+                write!(f, " {name}")?;
+            }
+            Ok(())
+        }
+        _ => write!(
+            f,
+            " {} {minor:0width$}/{scale}",
+            lang.conjunction(),
+            width = exp as usize
+        ),
+    }
+}
+
+/// Spells out `amount` (in minor units of `currency`) in words.
+pub fn to_words<L: Language + ?Sized, C: CurrencyUnit>(
+    lang: &L,
+    currency: C,
+    amount: u128,
+) -> String {
+    let mut s = String::new();
+    // writing to a `String` never fails
+    let _ = write_words(lang, &mut s, currency, amount, Style::Words);
+    s
+}
+
+/// Spells out `amount` (in minor units of `currency`) in words, with the minor part as a fraction.
+pub fn to_cheque_words<L: Language + ?Sized, C: CurrencyUnit>(
+    lang: &L,
+    currency: C,
+    amount: u128,
+) -> String {
+    let mut s = String::new();
+    // writing to a `String` never fails
+    let _ = write_words(lang, &mut s, currency, amount, Style::Cheque);
+    s
+}
+
+struct Capitalize<'a> {
+    inner: &'a mut dyn fmt::Write,
+    first: bool,
+}
+
+impl fmt::Write for Capitalize<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.first && !s.is_empty() {
+            self.first = false;
+            let mut chars = s.chars();
+            if let Some(c) = chars.next() {
+                for u in c.to_uppercase() {
+                    self.inner.write_char(u)?;
+                }
+            }
+            self.inner.write_str(chars.as_str())
+        } else {
+            self.inner.write_str(s)
+        }
+    }
+}
+
+/// American English amounts, e.g. `one hundred twenty-three dollars and forty-five cents`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct English;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// Short scale names, enough to cover `u128::MAX` (~340 undecillion).
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+impl English {
+    fn write_group(f: &mut dyn fmt::Write, n: usize) -> fmt::Result {
+        let (hundreds, rest) = (n / 100, n % 100);
+        if hundreds != 0 {
+            write!(f, "{} hundred", ONES[hundreds])?;
+            if rest != 0 {
+                f.write_char(' ')?;
+            }
+        }
+        match rest {
+            0 => Ok(()),
+            1..=19 => f.write_str(ONES[rest]),
+            _ if rest % 10 == 0 => f.write_str(TENS[rest / 10]),
+            _ => write!(f, "{}-{}", TENS[rest / 10], ONES[rest % 10]),
+        }
+    }
+}
+
+impl Language for English {
+    fn write_number(&self, f: &mut dyn fmt::Write, n: u128) -> fmt::Result {
+        if n == 0 {
+            return f.write_str(ONES[0]);
+        }
+
+        let mut groups = [0usize; SCALES.len()];
+        let mut rem = n;
+        for group in groups.iter_mut() {
+            *group = (rem % 1000) as usize;
+            rem /= 1000;
+        }
+
+        let mut first = true;
+        for (i, &group) in groups.iter().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+            if !first {
+                f.write_char(' ')?;
+            }
+            first = false;
+            Self::write_group(f, group)?;
+            if i != 0 {
+                write!(f, " {}", SCALES[i])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn unit_names(&self, currency: Currency) -> Option<UnitNames> {
+        english_unit_names(currency)
+    }
+
+    fn conjunction(&self) -> &'static str {
+        "and"
+    }
+}
+
+const DOLLAR: UnitNames = UnitNames::new("dollar", "dollars", "cent", "cents");
+const POUND_PENNY: UnitNames = UnitNames::new("pound", "pounds", "penny", "pence");
+const POUND_PIASTRE: UnitNames = UnitNames::new("pound", "pounds", "piastre", "piastres");
+const FRANC: UnitNames = UnitNames::new("franc", "francs", "centime", "centimes");
+const PESO: UnitNames = UnitNames::new("peso", "pesos", "centavo", "centavos");
+const DINAR_FILS: UnitNames = UnitNames::new("dinar", "dinars", "fils", "fils");
+const RUPEE_CENT: UnitNames = UnitNames::new("rupee", "rupees", "cent", "cents");
+const RUPEE_PAISA: UnitNames = UnitNames::new("rupee", "rupees", "paisa", "paise");
+const SHILLING: UnitNames = UnitNames::new("shilling", "shillings", "cent", "cents");
+const RIYAL: UnitNames = UnitNames::new("riyal", "riyals", "halala", "halalas");
+const LEU: UnitNames = UnitNames::new("leu", "lei", "ban", "bani");
+const KRONE: UnitNames = UnitNames::new("krone", "kroner", "øre", "øre");
+const RUBLE: UnitNames = UnitNames::new("ruble", "rubles", "kopeck", "kopecks");
+const BOLIVAR: UnitNames = UnitNames::new("bolívar", "bolívares", "céntimo", "céntimos");
+const KWACHA_TAMBALA: UnitNames = UnitNames::new("kwacha", "kwacha", "tambala", "tambala");
+const LEONE: UnitNames = UnitNames::new("leone", "leones", "cent", "cents");
+const MANAT: UnitNames = UnitNames::new("manat", "manat", "qəpik", "qəpik");
+
+/// English unit names, `None` for funds codes, precious metals and other non-cash units.
+const fn english_unit_names(currency: Currency) -> Option<UnitNames> {
+    use Currency::*;
+
+    let names = match currency {
+        AUD | BBD | BMD | BND | BSD | BZD | CAD | FJD | GYD | HKD | JMD | KYD | LRD | NAD | NZD
+        | SBD | SGD | SRD | TTD | TWD | USD | USN | XCD | ZWL => DOLLAR,
+        FKP | GBP | GIP | SHP => POUND_PENNY,
+        EGP | LBP | SDG | SSP | SYP => POUND_PIASTRE,
+        BIF | CDF | CHF | DJF | GNF | KMF | RWF | XAF | XOF | XPF => FRANC,
+        ARS | CLP | COP | CUP | DOP | MXN => PESO,
+        BHD | IQD | JOD | KWD => DINAR_FILS,
+        LKR | MUR | SCR => RUPEE_CENT,
+        INR | NPR | PKR => RUPEE_PAISA,
+        KES | SOS | TZS | UGX => SHILLING,
+        SAR => RIYAL,
+        MDL | RON => LEU,
+        DKK | NOK => KRONE,
+        BYN | RUB => RUBLE,
+        VED | VES => BOLIVAR,
+        MWK => KWACHA_TAMBALA,
+        SLE | SLL => LEONE,
+        AZN => MANAT,
+        AED => UnitNames::new("dirham", "dirhams", "fils", "fils"),
+        AFN => UnitNames::new("afghani", "afghanis", "pul", "puls"),
+        ALL => UnitNames::new("lek", "lekë", "qindarka", "qindarka"),
+        AMD => UnitNames::new("dram", "drams", "luma", "luma"),
+        ANG => UnitNames::new("guilder", "guilders", "cent", "cents"),
+        AOA => UnitNames::new("kwanza", "kwanzas", "cêntimo", "cêntimos"),
+        AWG => UnitNames::new("florin", "florins", "cent", "cents"),
+        BAM => UnitNames::new("convertible mark", "convertible marks", "fening", "fenings"),
+        BDT => UnitNames::new("taka", "taka", "poisha", "poisha"),
+        BGN => UnitNames::new("lev", "leva", "stotinka", "stotinki"),
+        BOB => UnitNames::new("boliviano", "bolivianos", "centavo", "centavos"),
+        BRL => UnitNames::new("real", "reais", "centavo", "centavos"),
+        BTN => UnitNames::new("ngultrum", "ngultrums", "chetrum", "chetrums"),
+        BWP => UnitNames::new("pula", "pula", "thebe", "thebe"),
+        CNY => UnitNames::new("yuan", "yuan", "fen", "fen"),
+        CRC => UnitNames::new("colón", "colones", "céntimo", "céntimos"),
+        CUC => UnitNames::new(
+            "convertible peso",
+            "convertible pesos",
+            "centavo",
+            "centavos",
+        ),
+        CVE => UnitNames::new("escudo", "escudos", "centavo", "centavos"),
+        CZK => UnitNames::new("koruna", "koruny", "haléř", "haléřů"),
+        DZD => UnitNames::new("dinar", "dinars", "santeem", "santeems"),
+        ERN => UnitNames::new("nakfa", "nakfa", "cent", "cents"),
+        ETB => UnitNames::new("birr", "birr", "santim", "santim"),
+        EUR => UnitNames::new("euro", "euros", "cent", "cents"),
+        GEL => UnitNames::new("lari", "lari", "tetri", "tetri"),
+        GHS => UnitNames::new("cedi", "cedis", "pesewa", "pesewas"),
+        GMD => UnitNames::new("dalasi", "dalasis", "butut", "bututs"),
+        GTQ => UnitNames::new("quetzal", "quetzales", "centavo", "centavos"),
+        HNL => UnitNames::new("lempira", "lempiras", "centavo", "centavos"),
+        HTG => UnitNames::new("gourde", "gourdes", "centime", "centimes"),
+        HUF => UnitNames::new("forint", "forints", "fillér", "fillér"),
+        IDR => UnitNames::new("rupiah", "rupiah", "sen", "sen"),
+        ILS => UnitNames::new("shekel", "shekels", "agora", "agorot"),
+        IRR => UnitNames::new("rial", "rials", "dinar", "dinars"),
+        ISK => UnitNames::new("króna", "krónur", "eyrir", "aurar"),
+        JPY => UnitNames::new("yen", "yen", "sen", "sen"),
+        KGS => UnitNames::new("som", "som", "tyiyn", "tyiyn"),
+        KHR => UnitNames::new("riel", "riels", "sen", "sen"),
+        KPW => UnitNames::new("won", "won", "chon", "chon"),
+        KRW => UnitNames::new("won", "won", "jeon", "jeon"),
+        KZT => UnitNames::new("tenge", "tenge", "tiyn", "tiyn"),
+        LAK => UnitNames::new("kip", "kip", "att", "att"),
+        LSL => UnitNames::new("loti", "maloti", "sente", "lisente"),
+        LYD => UnitNames::new("dinar", "dinars", "dirham", "dirhams"),
+        MAD => UnitNames::new("dirham", "dirhams", "centime", "centimes"),
+        // 1 ariary = 5 iraimbilanja, but ISO 4217 lists 2 decimal minor units
+        MGA => UnitNames::major_only("ariary", "ariary"),
+        MKD => UnitNames::new("denar", "denars", "deni", "deni"),
+        MMK => UnitNames::new("kyat", "kyats", "pya", "pyas"),
+        MNT => UnitNames::new("tögrög", "tögrögs", "möngö", "möngö"),
+        MOP => UnitNames::new("pataca", "patacas", "avo", "avos"),
+        // 1 ouguiya = 5 khoums, but ISO 4217 lists 2 decimal minor units
+        MRU => UnitNames::major_only("ouguiya", "ouguiyas"),
+        MVR => UnitNames::new("rufiyaa", "rufiyaa", "laari", "laari"),
+        MYR => UnitNames::new("ringgit", "ringgit", "sen", "sen"),
+        MZN => UnitNames::new("metical", "meticais", "centavo", "centavos"),
+        NGN => UnitNames::new("naira", "naira", "kobo", "kobo"),
+        NIO => UnitNames::new("córdoba", "córdobas", "centavo", "centavos"),
+        OMR => UnitNames::new("rial", "rials", "baisa", "baisa"),
+        PAB => UnitNames::new("balboa", "balboas", "centésimo", "centésimos"),
+        PEN => UnitNames::new("sol", "soles", "céntimo", "céntimos"),
+        PGK => UnitNames::new("kina", "kina", "toea", "toea"),
+        PHP => UnitNames::new("peso", "pesos", "sentimo", "sentimo"),
+        PLN => UnitNames::new("złoty", "złoty", "grosz", "groszy"),
+        PYG => UnitNames::new("guaraní", "guaraníes", "céntimo", "céntimos"),
+        QAR => UnitNames::new("riyal", "riyals", "dirham", "dirhams"),
+        RSD => UnitNames::new("dinar", "dinars", "para", "para"),
+        SEK => UnitNames::new("krona", "kronor", "öre", "öre"),
+        STN => UnitNames::new("dobra", "dobras", "cêntimo", "cêntimos"),
+        SVC => UnitNames::new("colón", "colones", "centavo", "centavos"),
+        SZL => UnitNames::new("lilangeni", "emalangeni", "cent", "cents"),
+        THB => UnitNames::new("baht", "baht", "satang", "satang"),
+        TJS => UnitNames::new("somoni", "somoni", "diram", "diram"),
+        TMT => UnitNames::new("manat", "manat", "tenge", "tenge"),
+        TND => UnitNames::new("dinar", "dinars", "millime", "millimes"),
+        TOP => UnitNames::new("paʻanga", "paʻanga", "seniti", "seniti"),
+        TRY => UnitNames::new("lira", "lira", "kuruş", "kuruş"),
+        UAH => UnitNames::new("hryvnia", "hryvnias", "kopiyka", "kopiyky"),
+        UYU => UnitNames::new("peso", "pesos", "centésimo", "centésimos"),
+        UZS => UnitNames::new("sum", "sum", "tiyin", "tiyin"),
+        VND => UnitNames::new("đồng", "đồng", "hào", "hào"),
+        VUV => UnitNames::new("vatu", "vatu", "vatu", "vatu"),
+        WST => UnitNames::new("tala", "tala", "sene", "sene"),
+        YER => UnitNames::new("rial", "rials", "fils", "fils"),
+        ZAR => UnitNames::new("rand", "rand", "cent", "cents"),
+        ZMW => UnitNames::new("kwacha", "kwacha", "ngwee", "ngwee"),
+        _ => return None,
+    };
+
+    Some(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cheque_words() {
+        assert_eq!(
+            to_cheque_words(&English, Currency::USD, 12345),
+            "One hundred twenty-three dollars and 45/100"
+        );
+        assert_eq!(
+            to_cheque_words(&English, Currency::USD, 100),
+            "One dollar and 00/100"
+        );
+        assert_eq!(
+            to_cheque_words(&English, Currency::USD, 0),
+            "Zero dollars and 00/100"
+        );
+        assert_eq!(
+            to_cheque_words(&English, Currency::KWD, 12_345),
+            "Twelve dinars and 345/1000"
+        );
+        assert_eq!(
+            to_cheque_words(&English, Currency::JPY, 12_345),
+            "Twelve thousand three hundred forty-five yen"
+        );
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(
+            to_words(&English, Currency::USD, 12345),
+            "One hundred twenty-three dollars and forty-five cents"
+        );
+        assert_eq!(
+            to_words(&English, Currency::GBP, 101),
+            "One pound and one penny"
+        );
+        assert_eq!(to_words(&English, Currency::EUR, 2_000), "Twenty euros");
+        assert_eq!(
+            to_words(&English, Currency::KWD, 1_005),
+            "One dinar and five fils"
+        );
+        assert_eq!(
+            to_words(&English, Currency::MRU, 101),
+            "One ouguiya and 01/100"
+        );
+        assert_eq!(
+            to_words(&English, Currency::MGA, 1_500),
+            "Fifteen ariary and 00/100"
+        );
+        assert_eq!(to_words(&English, Currency::XAU, 7), "Seven XAU");
+        assert_eq!(to_words(&English, Currency::CHE, 250), "Two CHE and 50/100");
+    }
+
+    #[test]
+    fn test_large_amounts() {
+        assert_eq!(
+            to_words(&English, Currency::JPY, 1_000_000_001),
+            "One billion one yen"
+        );
+        assert_eq!(
+            to_words(&English, Currency::JPY, u128::MAX),
+            "Three hundred forty undecillion two hundred eighty-two decillion three hundred \
+             sixty-six nonillion nine hundred twenty octillion nine hundred thirty-eight \
+             septillion four hundred sixty-three sextillion four hundred sixty-three \
+             quintillion three hundred seventy-four quadrillion six hundred seven trillion \
+             four hundred thirty-one billion seven hundred sixty-eight million two hundred \
+             eleven thousand four hundred fifty-five yen"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Runs the C harness in `tests/ffi/harness.c` against the C API, and checks that
+//! `include/iso4217.h` matches the Rust declarations in `src/ffi.rs`.
+#![cfg(feature = "ffi")]
+
+use std::ffi::c_int;
+
+use currency_iso4217::ffi;
+
+#[link(name = "iso4217_ffi_harness", kind = "static")]
+extern "C" {
+    fn iso4217_ffi_harness() -> c_int;
+}
+
+const HEADER: &str = include_str!("../include/iso4217.h");
+const RUST: &str = include_str!("../src/ffi.rs");
+
+#[test]
+fn test_c_harness() {
+    // Keep the exported functions linked for the harness.
+    assert_eq!(ffi::iso4217_from_numeric(840), 840);
+
+    assert_eq!(unsafe { iso4217_ffi_harness() }, 0, "C checks failed");
+}
+
+/// Spells a Rust FFI type as a C declaration of `name`.
+fn declare(ty: &str, name: &str) -> String {
+    let ty = match ty {
+        "u16" => "uint16_t",
+        "c_int" => "int",
+        "*const c_char" => "const char *",
+        _ => panic!("no C type for `{ty}` in src/ffi.rs"),
+    };
+    if ty.ends_with('*') {
+        format!("{ty}{name}")
+    } else {
+        format!("{ty} {name}")
+    }
+}
+
+/// Gets the C prototypes of the `extern "C"` functions in `src/ffi.rs`.
+fn rust_prototypes() -> Vec<String> {
+    RUST.split("extern \"C\" fn ")
+        .skip(1)
+        .map(|item| {
+            let sig = item[..item.find('{').unwrap()]
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+            let (name, rest) = sig.split_once('(').unwrap();
+            let (params, ret) = rest.rsplit_once(") -> ").unwrap();
+            let params = params
+                .trim_end_matches(',')
+                .split(", ")
+                .map(|param| {
+                    let (name, ty) = param.split_once(": ").unwrap();
+                    declare(ty, name)
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{}({params});", declare(ret, name))
+        })
+        .collect()
+}
+
+/// Gets the function prototypes declared in the header, with comments and whitespace removed.
+fn header_prototypes() -> Vec<String> {
+    let mut code = String::new();
+    let mut rest = HEADER;
+    while let Some(start) = rest.find("/*") {
+        code.push_str(&rest[..start]);
+        let end = rest[start..].find("*/").unwrap();
+        rest = &rest[start + end + 2..];
+    }
+    code.push_str(rest);
+
+    let code = code
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#') && *line != "extern \"C\" {" && *line != "}")
+        .collect::<Vec<_>>()
+        .join(" ");
+    code.split_inclusive(';')
+        .map(|decl| decl.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|decl| decl.contains('('))
+        .collect()
+}
+
+#[test]
+fn test_header_prototypes() {
+    let rust = rust_prototypes();
+    assert_eq!(rust.len(), 5);
+    assert_eq!(
+        header_prototypes(),
+        rust,
+        "include/iso4217.h is out of date with src/ffi.rs"
+    );
+}
+
+#[test]
+fn test_header_constants() {
+    let rust: Vec<(&str, &str)> = RUST
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub const "))
+        .map(|line| {
+            let (name, value) = line.split_once(": c_int = ").unwrap();
+            (name, value.trim_end_matches(';'))
+        })
+        .collect();
+    let header: Vec<(&str, &str)> = HEADER
+        .lines()
+        .filter_map(|line| line.strip_prefix("#define "))
+        .filter_map(|line| line.split_once(' '))
+        .map(|(name, value)| (name, value.trim_start_matches('(').trim_end_matches(')')))
+        .collect();
+
+    assert_eq!(rust.len(), 2);
+    assert_eq!(
+        header, rust,
+        "include/iso4217.h is out of date with src/ffi.rs"
+    );
+}
@@ -0,0 +1,54 @@
+/* Exercises the C API through include/iso4217.h, run by tests/ffi.rs. */
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "iso4217.h"
+
+static int failures;
+
+#define CHECK(cond)                                                             \
+    do {                                                                        \
+        if (!(cond)) {                                                          \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,    \
+                    #cond);                                                     \
+            failures++;                                                         \
+        }                                                                       \
+    } while (0)
+
+static int str_eq(const char *a, const char *b) {
+    return a != NULL && b != NULL && strcmp(a, b) == 0;
+}
+
+int iso4217_ffi_harness(void) {
+    failures = 0;
+
+    CHECK(iso4217_from_alpha("USD") == 840);
+    CHECK(iso4217_from_alpha("eur") == 978);
+    CHECK(iso4217_from_alpha("QQQ") == 0);
+    CHECK(iso4217_from_alpha("USDX") == 0);
+    CHECK(iso4217_from_alpha("") == 0);
+    CHECK(iso4217_from_alpha(NULL) == 0);
+
+    CHECK(iso4217_from_numeric(392) == 392);
+    CHECK(iso4217_from_numeric(0) == 0);
+    CHECK(iso4217_from_numeric(999) == 999);
+    CHECK(iso4217_from_numeric(1000) == 0);
+
+    CHECK(str_eq(iso4217_alpha(840), "USD"));
+    CHECK(str_eq(iso4217_alpha(8), "ALL"));
+    CHECK(iso4217_alpha(0) == NULL);
+    CHECK(iso4217_alpha(iso4217_from_alpha("chf")) == iso4217_alpha(756));
+
+    CHECK(str_eq(iso4217_name(840), "US Dollar"));
+    CHECK(str_eq(iso4217_name(978), "Euro"));
+    CHECK(iso4217_name(1) == NULL);
+
+    CHECK(iso4217_minor_units(840) == 2);
+    CHECK(iso4217_minor_units(392) == 0);
+    CHECK(iso4217_minor_units(48) == 3);
+    CHECK(iso4217_minor_units(959) == ISO4217_MINOR_UNITS_NA);
+    CHECK(iso4217_minor_units(0) == ISO4217_UNKNOWN);
+
+    return failures;
+}